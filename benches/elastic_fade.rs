@@ -0,0 +1,68 @@
+//! Measures a full-screen elastic fade, and compares the cost of evaluating
+//! the easing function once per frame, as the effect core does, against
+//! evaluating it once per cell.
+//!
+//! Run with `cargo bench --bench elastic_fade`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use tachyonfx::{fx, ColorMapper, Interpolatable, Interpolation, Shader};
+
+const FRAMES: u32 = 600;
+const FRAME_TIME: Duration = Duration::from_millis(16);
+
+fn main() {
+    let area = Rect::new(0, 0, 240, 80);
+    let lifetime = FRAME_TIME * FRAMES;
+    let progress = |frame: u32| (FRAME_TIME * (frame + 1)).as_secs_f32() / lifetime.as_secs_f32();
+
+    bench("fx::fade_to_fg", area, |buf| {
+        let mut fade = fx::fade_to_fg(Color::Red, (lifetime, Interpolation::ElasticOut));
+        for _ in 0..FRAMES {
+            fade.process(FRAME_TIME, buf, area);
+        }
+    });
+
+    let per_frame = bench("easing evaluated per frame", area, |buf| {
+        for frame in 0..FRAMES {
+            let alpha = Interpolation::ElasticOut.alpha(black_box(progress(frame)));
+            let mut mapper = ColorMapper::default();
+            for cell in buf.content.iter_mut() {
+                cell.fg = mapper.map(cell.fg, alpha, |c| c.lerp(&Color::Red, alpha));
+            }
+        }
+    });
+
+    let per_cell = bench("easing evaluated per cell", area, |buf| {
+        for frame in 0..FRAMES {
+            let mut mapper = ColorMapper::default();
+            for cell in buf.content.iter_mut() {
+                let alpha = Interpolation::ElasticOut.alpha(black_box(progress(frame)));
+                cell.fg = mapper.map(cell.fg, alpha, |c| c.lerp(&Color::Red, alpha));
+            }
+        }
+    });
+
+    println!("per frame vs per cell: {:.2}x", per_cell.as_secs_f64() / per_frame.as_secs_f64());
+}
+
+fn bench(name: &str, area: Rect, f: impl Fn(&mut Buffer)) -> Duration {
+    let mut buf = Buffer::empty(area);
+    let start = Instant::now();
+    f(&mut buf);
+    let elapsed = start.elapsed();
+
+    black_box(&buf);
+    println!(
+        "{name}: {:?} total, {:?}/frame over {} cells",
+        elapsed,
+        elapsed / FRAMES,
+        area.area()
+    );
+
+    elapsed
+}
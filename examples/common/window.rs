@@ -43,7 +43,7 @@ impl OpenWindow {
         }
     }
 
-    fn window_block(&self) -> Block {
+    fn window_block(&self) -> Block<'_> {
         Block::new()
             .borders(Borders::ALL)
             .title_style(self.title_style)
@@ -82,8 +82,7 @@ impl Shader for OpenWindow {
         };
 
         let area = self.pre_render_fx.as_ref()
-            .map(Effect::area)
-            .flatten()
+            .and_then(Effect::area)
             .map(|area| area.clamp(buf.area))
             .unwrap_or(area);
 
@@ -132,6 +131,6 @@ impl Shader for OpenWindow {
     }
 
     fn cell_selection(&self) -> Option<CellFilter> {
-        self.pre_render_fx.as_ref().map(Effect::cell_selection).flatten()
+        self.pre_render_fx.as_ref().and_then(Effect::cell_selection)
     }
 }
\ No newline at end of file
@@ -54,8 +54,8 @@ fn main() -> Result<()> {
     let res = run_app(&mut terminal, app);
 
     // restore terminal
-    let _ = disable_raw_mode().expect("failed to disable raw mode");
-    let _ = execute!(
+    disable_raw_mode().expect("failed to disable raw mode");
+    execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
         DisableMouseCapture
@@ -82,11 +82,8 @@ fn run_app(
         while last_frame_instant.elapsed() < Duration::from_millis(32) {
             if event::poll(Duration::from_millis(5))? {
                 if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press {
-                        match key.code {
-                            KeyCode::Esc => return Ok(()),
-                            _ => {}
-                        }
+                    if key.kind == KeyEventKind::Press && key.code == KeyCode::Esc {
+                        return Ok(())
                     }
                 }
             }
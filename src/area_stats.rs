@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::color_ext::Luminance;
+
+/// Statistics about the content of an area of a buffer, as returned by
+/// [analyze_area]. Useful for effects, or applications, adapting to the
+/// content they are applied to.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AreaStats {
+    /// The number of cells in the area.
+    pub cell_count: usize,
+    /// The number of cells with a symbol other than a space.
+    pub non_blank_count: usize,
+    /// The average luminance of the foreground colors of non-blank cells.
+    pub fg_luminance: f32,
+    /// The average luminance of the background colors of all cells.
+    pub bg_luminance: f32,
+    /// The foreground colors of non-blank cells and their number of cells, most frequent first.
+    pub fg_colors: Vec<(Color, usize)>,
+    /// The background colors of all cells and their number of cells, most frequent first.
+    pub bg_colors: Vec<(Color, usize)>,
+    /// The smallest rectangle containing all non-blank cells, if any.
+    pub content_bounds: Option<Rect>,
+}
+
+impl AreaStats {
+    /// Returns the most frequent foreground color of non-blank cells.
+    pub fn dominant_fg(&self) -> Option<Color> {
+        self.fg_colors.first().map(|(color, _)| *color)
+    }
+
+    /// Returns the most frequent background color.
+    pub fn dominant_bg(&self) -> Option<Color> {
+        self.bg_colors.first().map(|(color, _)| *color)
+    }
+}
+
+/// Collects [AreaStats] about the cells of `area` in a single pass over the buffer.
+/// The area is clipped to the buffer.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::analyze_area;
+///
+/// let mut buf = Buffer::with_lines(vec!["    ", " ab ", "    "]);
+/// buf.set_style(Rect::new(0, 0, 4, 3), (Color::White, Color::Blue));
+/// buf.get_mut(0, 0).set_bg(Color::Black);
+///
+/// let stats = analyze_area(&buf, buf.area);
+/// assert_eq!(stats.cell_count, 12);
+/// assert_eq!(stats.non_blank_count, 2);
+/// assert_eq!(stats.content_bounds, Some(Rect::new(1, 1, 2, 1)));
+/// assert_eq!(stats.dominant_fg(), Some(Color::White));
+/// assert_eq!(stats.bg_colors, vec![(Color::Blue, 11), (Color::Black, 1)]);
+/// ```
+pub fn analyze_area(buf: &Buffer, area: Rect) -> AreaStats {
+    let area = area.intersection(buf.area);
+
+    let mut fg_colors = ColorHistogram::default();
+    let mut bg_colors = ColorHistogram::default();
+    let mut fg_luminance = 0.0;
+    let mut bg_luminance = 0.0;
+    let mut non_blank_count = 0;
+    let mut bounds: Option<(u16, u16, u16, u16)> = None;
+
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell = buf.get(x, y);
+
+            bg_colors.add(cell.bg);
+            bg_luminance += cell.bg.luminance();
+
+            if cell.symbol() != " " {
+                non_blank_count += 1;
+                fg_colors.add(cell.fg);
+                fg_luminance += cell.fg.luminance();
+
+                bounds = Some(match bounds {
+                    None => (x, y, x, y),
+                    Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+                });
+            }
+        }
+    }
+
+    let cell_count = area.area() as usize;
+    let average = |sum: f32, n: usize| if n > 0 { sum / n as f32 } else { 0.0 };
+
+    AreaStats {
+        cell_count,
+        non_blank_count,
+        fg_luminance: average(fg_luminance, non_blank_count),
+        bg_luminance: average(bg_luminance, cell_count),
+        fg_colors: fg_colors.into_sorted(),
+        bg_colors: bg_colors.into_sorted(),
+        content_bounds: bounds
+            .map(|(x0, y0, x1, y1)| Rect::new(x0, y0, x1 - x0 + 1, y1 - y0 + 1)),
+    }
+}
+
+/// Counts colors, remembering the order in which they were first seen
+/// to break ties.
+#[derive(Default)]
+struct ColorHistogram {
+    index: HashMap<Color, usize>,
+    counts: Vec<(Color, usize)>,
+}
+
+impl ColorHistogram {
+    fn add(&mut self, color: Color) {
+        let counts = &mut self.counts;
+        let idx = *self.index.entry(color).or_insert_with(|| {
+            counts.push((color, 0));
+            counts.len() - 1
+        });
+        self.counts[idx].1 += 1;
+    }
+
+    fn into_sorted(mut self) -> Vec<(Color, usize)> {
+        self.counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        self.counts
+    }
+}
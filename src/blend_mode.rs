@@ -0,0 +1,81 @@
+use ratatui::buffer::Cell;
+use ratatui::style::Color;
+
+use crate::color_ext::ToRgbComponents;
+use crate::Interpolatable;
+
+/// Determines how a color written by an effect (the layer) is combined
+/// with the color already present in the cell (the base).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BlendMode {
+    /// The layer replaces the base.
+    #[default]
+    Replace,
+    /// The layer and base are averaged.
+    Average,
+    /// The RGB channels are multiplied, darkening the result.
+    Multiply,
+    /// The inverted RGB channels are multiplied, lightening the result.
+    Screen,
+}
+
+impl BlendMode {
+    /// Blends the `layer` color onto the `base` color.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tachyonfx::BlendMode;
+    ///
+    /// let base = Color::Rgb(200, 100, 0);
+    /// let layer = Color::Rgb(100, 100, 100);
+    ///
+    /// assert_eq!(BlendMode::Replace.blend(base, layer), layer);
+    /// assert_eq!(BlendMode::Average.blend(base, layer), Color::Rgb(150, 100, 50));
+    /// assert_eq!(BlendMode::Multiply.blend(base, layer), Color::Rgb(78, 39, 0));
+    /// ```
+    pub fn blend(&self, base: Color, layer: Color) -> Color {
+        let channels = |f: fn(f32, f32) -> f32| {
+            let (r1, g1, b1) = base.to_rgb();
+            let (r2, g2, b2) = layer.to_rgb();
+            let c = |a: u8, b: u8| (f(a as f32 / 255.0, b as f32 / 255.0) * 255.0)
+                .round()
+                .clamp(0.0, 255.0) as u8;
+
+            Color::Rgb(c(r1, r2), c(g1, g2), c(b1, b2))
+        };
+
+        match self {
+            BlendMode::Replace  => layer,
+            BlendMode::Average  => channels(|a, b| (a + b) / 2.0),
+            BlendMode::Multiply => channels(|a, b| a * b),
+            BlendMode::Screen   => channels(|a, b| 1.0 - (1.0 - a) * (1.0 - b)),
+        }
+    }
+
+    /// Blends the `layer` cell onto the `base` cell, at the given opacity. The
+    /// colors are blended according to the blend mode and then interpolated from
+    /// the base colors by `alpha`; the layer's symbol and modifiers are applied
+    /// once `alpha` reaches `0.5`.
+    pub fn blend_cell(&self, base: &Cell, layer: &Cell, alpha: f32) -> Cell {
+        let mut cell = base.clone();
+
+        if alpha >= 0.5 {
+            cell.set_symbol(layer.symbol());
+            cell.modifier = layer.modifier;
+        }
+
+        let blend = |base: Color, layer: Color| -> Color {
+            if base == layer {
+                base
+            } else {
+                base.lerp(&self.blend(base, layer), alpha)
+            }
+        };
+
+        cell.fg = blend(base.fg, layer.fg);
+        cell.bg = blend(base.bg, layer.bg);
+
+        cell
+    }
+}
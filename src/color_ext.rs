@@ -1,3 +1,6 @@
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
 use ratatui::style::Color;
 
 pub trait ToRgbComponents {
@@ -6,33 +9,254 @@ pub trait ToRgbComponents {
 
 impl ToRgbComponents for Color {
     fn to_rgb(&self) -> (u8, u8, u8) {
-        match self {
-            Color::Rgb(r, g, b) => (*r, *g, *b),
-            Color::Reset => (0, 0, 0),
-            Color::Black => (0, 0, 0),
-            Color::Red => (255, 0, 0),
-            Color::Green => (0, 255, 0),
-            Color::Yellow => (255, 255, 0),
-            Color::Blue => (0, 0, 255),
-            Color::Magenta => (255, 0, 255),
-            Color::Cyan => (0, 255, 255),
-            Color::Gray => (128, 128, 128),
-            Color::DarkGray => (64, 64, 64),
-            Color::LightRed => (255, 128, 128),
-            Color::LightGreen => (128, 255, 128),
-            Color::LightYellow => (255, 255, 128),
-            Color::LightBlue => (128, 128, 255),
-            Color::LightMagenta => (255, 128, 255),
-            Color::LightCyan => (128, 255, 255),
-            Color::White => (255, 255, 255),
-            Color::Indexed(code) => {
-                let rgb = colorsys::Ansi256::new(*code).as_rgb();
-                (rgb.red().round() as u8, rgb.green().round() as u8, rgb.blue().round() as u8)
-            },
+        if !CUSTOM_RESOLVER.load(Ordering::Acquire) {
+            return default_rgb(*self);
+        }
+
+        if let Some(slot) = palette_slot(*self) {
+            return unpack_rgb(PALETTE[slot].load(Ordering::Relaxed));
         }
+
+        // rgb colors and the terminal's default color are resolved on demand
+        let installed = COLOR_RESOLVER.read().unwrap_or_else(|e| e.into_inner());
+        installed.as_ref()
+            .and_then(|resolver| resolver.resolve(*self))
+            .unwrap_or_else(|| default_rgb(*self))
+    }
+}
+
+/// Resolves terminal colors to RGB, for effects which interpolate or otherwise
+/// compute colors. Implement it to supply the palette of the actual terminal,
+/// e.g. the exact ANSI colors of the user's theme, and install it with
+/// [set_color_resolver].
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{set_color_resolver, ColorResolver, Interpolatable};
+///
+/// struct Theme;
+///
+/// impl ColorResolver for Theme {
+///     fn resolve(&self, color: Color) -> Option<(u8, u8, u8)> {
+///         match color {
+///             Color::Black => Some((40, 40, 40)),
+///             Color::White => Some((240, 240, 240)),
+///             _            => None, // falls back to the default palette
+///         }
+///     }
+/// }
+///
+/// set_color_resolver(Theme);
+/// assert_eq!(Color::Black.lerp(&Color::White, 0.5), Color::Rgb(140, 140, 140));
+/// ```
+pub trait ColorResolver: Send + Sync {
+    /// Returns the RGB components of the color, or `None` to fall back to the
+    /// [DefaultColorResolver].
+    fn resolve(&self, color: Color) -> Option<(u8, u8, u8)>;
+}
+
+/// Resolves named colors to their nominal values, e.g. `Color::Red` to
+/// `(255, 0, 0)`, and indexed colors according to the xterm 256 color palette.
+/// [Color::Reset] resolves to black.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultColorResolver;
+
+impl ColorResolver for DefaultColorResolver {
+    fn resolve(&self, color: Color) -> Option<(u8, u8, u8)> {
+        Some(default_rgb(color))
+    }
+}
+
+/// The number of colors in [PALETTE]: the 256 indexed colors, followed by the
+/// 16 named colors.
+const PALETTE_LEN: usize = 256 + 16;
+
+static CUSTOM_RESOLVER: AtomicBool = AtomicBool::new(false);
+static COLOR_RESOLVER: RwLock<Option<Box<dyn ColorResolver>>> = RwLock::new(None);
+
+/// The indexed and named colors as resolved by the installed resolver, packed
+/// as `0xRRGGBB`; see [palette_slot].
+static PALETTE: [AtomicU32; PALETTE_LEN] = [const { AtomicU32::new(0) }; PALETTE_LEN];
+
+/// Returns the slot of the color in [PALETTE], or `None` for [Color::Rgb] and
+/// [Color::Reset].
+fn palette_slot(color: Color) -> Option<usize> {
+    let named = match color {
+        Color::Indexed(code) => return Some(code as usize),
+        Color::Rgb(..) | Color::Reset => return None,
+        Color::Black        => 0,
+        Color::Red          => 1,
+        Color::Green        => 2,
+        Color::Yellow       => 3,
+        Color::Blue         => 4,
+        Color::Magenta      => 5,
+        Color::Cyan         => 6,
+        Color::Gray         => 7,
+        Color::DarkGray     => 8,
+        Color::LightRed     => 9,
+        Color::LightGreen   => 10,
+        Color::LightYellow  => 11,
+        Color::LightBlue    => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan    => 14,
+        Color::White        => 15,
+    };
+
+    Some(256 + named)
+}
+
+/// Returns the color stored in the [PALETTE] slot.
+fn slot_color(slot: usize) -> Color {
+    const NAMED: [Color; 16] = [
+        Color::Black, Color::Red, Color::Green, Color::Yellow,
+        Color::Blue, Color::Magenta, Color::Cyan, Color::Gray,
+        Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+        Color::LightBlue, Color::LightMagenta, Color::LightCyan, Color::White,
+    ];
+
+    match slot {
+        0..=255 => Color::Indexed(slot as u8),
+        _       => NAMED[slot - 256],
+    }
+}
+
+fn pack_rgb((r, g, b): (u8, u8, u8)) -> u32 {
+    (r as u32) << 16 | (g as u32) << 8 | b as u32
+}
+
+fn unpack_rgb(rgb: u32) -> (u8, u8, u8) {
+    ((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+/// Installs the [ColorResolver] used by all effects to resolve colors to RGB,
+/// replacing the [DefaultColorResolver]. Downsampling to indexed colors, as by
+/// [term256_colors](crate::fx::term256_colors), matches against the palette as
+/// resolved by the installed resolver.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::{fx, reset_color_resolver, set_color_resolver, ColorResolver, Shader};
+///
+/// struct Theme;
+///
+/// impl ColorResolver for Theme {
+///     fn resolve(&self, color: Color) -> Option<(u8, u8, u8)> {
+///         match color {
+///             Color::Red | Color::Indexed(1) => Some((90, 30, 200)),
+///             _                             => None,
+///         }
+///     }
+/// }
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let downsampled = || {
+///     let mut buf = Buffer::empty(area);
+///     buf.set_style(area, Style::default().bg(Color::Rgb(90, 30, 200)));
+///     fx::term256_colors().process(Duration::ZERO, &mut buf, area);
+///     buf.get(0, 0).bg
+/// };
+///
+/// set_color_resolver(Theme);
+/// assert_eq!(downsampled(), Color::Indexed(1));
+///
+/// // back to the default palette
+/// reset_color_resolver();
+/// assert_ne!(downsampled(), Color::Indexed(1));
+/// ```
+pub fn set_color_resolver<R: ColorResolver + 'static>(resolver: R) {
+    let mut installed = COLOR_RESOLVER.write().unwrap_or_else(|e| e.into_inner());
+    PALETTE.iter().enumerate().for_each(|(slot, rgb)| {
+        let color = slot_color(slot);
+        let resolved = resolver.resolve(color).unwrap_or_else(|| default_rgb(color));
+        rgb.store(pack_rgb(resolved), Ordering::Relaxed);
+    });
+
+    *installed = Some(Box::new(resolver));
+    CUSTOM_RESOLVER.store(true, Ordering::Release);
+}
+
+/// Removes the resolver installed with [set_color_resolver], restoring the
+/// [DefaultColorResolver].
+pub fn reset_color_resolver() {
+    let mut installed = COLOR_RESOLVER.write().unwrap_or_else(|e| e.into_inner());
+    *installed = None;
+    CUSTOM_RESOLVER.store(false, Ordering::Release);
+}
+
+fn default_rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Reset => (0, 0, 0),
+        Color::Black => (0, 0, 0),
+        Color::Red => (255, 0, 0),
+        Color::Green => (0, 255, 0),
+        Color::Yellow => (255, 255, 0),
+        Color::Blue => (0, 0, 255),
+        Color::Magenta => (255, 0, 255),
+        Color::Cyan => (0, 255, 255),
+        Color::Gray => (128, 128, 128),
+        Color::DarkGray => (64, 64, 64),
+        Color::LightRed => (255, 128, 128),
+        Color::LightGreen => (128, 255, 128),
+        Color::LightYellow => (255, 255, 128),
+        Color::LightBlue => (128, 128, 255),
+        Color::LightMagenta => (255, 128, 255),
+        Color::LightCyan => (128, 255, 255),
+        Color::White => (255, 255, 255),
+        Color::Indexed(code) => {
+            let rgb = colorsys::Ansi256::new(code).as_rgb();
+            (rgb.red().round() as u8, rgb.green().round() as u8, rgb.blue().round() as u8)
+        },
+    }
+}
+
+pub trait Luminance {
+    /// Returns the perceived brightness of the color, in the range `0.0..=1.0`,
+    /// weighting the RGB channels by their contribution to human perception.
+    fn luminance(&self) -> f32;
+}
+
+impl Luminance for Color {
+    fn luminance(&self) -> f32 {
+        let (r, g, b) = self.to_rgb();
+        (0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32) / 255.0
     }
 }
 
+/// Returns the WCAG contrast ratio between two colors, ranging from `1.0` (no
+/// contrast) to `21.0` (black on white). WCAG recommends a ratio of at least `4.5`
+/// for body text.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::contrast_ratio;
+///
+/// let black_on_white = contrast_ratio(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255));
+/// assert!((black_on_white - 21.0).abs() < 0.001);
+/// assert_eq!(contrast_ratio(Color::Rgb(90, 90, 90), Color::Rgb(90, 90, 90)), 1.0);
+/// ```
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (l1, l2) = (relative_luminance(a), relative_luminance(b));
+    (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+}
+
+/// The WCAG relative luminance of the color, computed on linearized sRGB channels.
+fn relative_luminance(color: Color) -> f32 {
+    let linear = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    };
+
+    let (r, g, b) = color.to_rgb();
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
 pub trait AsIndexedColor {
     fn as_indexed_color(&self) -> Color;
 }
@@ -41,8 +265,25 @@ impl AsIndexedColor for Color {
     fn as_indexed_color(&self) -> Color {
         let (r, g, b) = self.to_rgb();
 
+        if CUSTOM_RESOLVER.load(Ordering::Acquire) {
+            let palette = std::array::from_fn(|code| unpack_rgb(PALETTE[code].load(Ordering::Relaxed)));
+            return Color::Indexed(nearest_index(&palette, (r, g, b)));
+        }
+
         let c = colorsys::Rgb::from([r as f64, g as f64, b as f64]);
         let ansi256 = colorsys::Ansi256::from(c);
         Color::Indexed(ansi256.code())
     }
+}
+
+/// Returns the index of the palette color closest to `rgb`.
+fn nearest_index(palette: &[(u8, u8, u8); 256], rgb: (u8, u8, u8)) -> u8 {
+    let distance = |c: &(u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(c.0, rgb.0) + d(c.1, rgb.1) + d(c.2, rgb.2)
+    };
+
+    (0..=255u8)
+        .min_by_key(|code| distance(&palette[*code as usize]))
+        .unwrap()
 }
\ No newline at end of file
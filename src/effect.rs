@@ -1,9 +1,12 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
 use std::time::Duration;
 
 use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::{Margin, Position, Rect};
 use ratatui::style::Color;
-use crate::{CellIterator, EffectTimer};
+use crate::{reduced_motion, CellIterator, EffectTimer, EffectTree, EffectWarning, OvershootMode};
 use crate::shader::Shader;
 
 /// Represents an effect that can be applied to terminal cells.
@@ -11,6 +14,71 @@ use crate::shader::Shader;
 /// and applied to a specified area and cell selection.
 pub struct Effect {
     shader: Box<dyn Shader>,
+    reduced_motion: Option<bool>,
+    last_consumed: Duration,
+    clamp_policy: ClampPolicy,
+    out_of_bounds: Option<OutOfBounds>,
+    elapsed: Duration,
+    marks: Vec<Mark>,
+    fired: usize,
+    skipped: bool,
+}
+
+type MarkFn = Rc<RefCell<Box<dyn FnMut()>>>;
+
+/// A callback registered with [Effect::at]. Clones of the effect share the
+/// callback, but track which marks they have crossed on their own.
+#[derive(Clone)]
+struct Mark {
+    at: Duration,
+    f: MarkFn,
+}
+
+/// Determines how an [Effect] handles an area which extends beyond the buffer
+/// it is processed in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClampPolicy {
+    /// Leaves the area as it is, which must then lie within the buffer.
+    #[default]
+    Passthrough,
+    /// Moves the area inside the buffer, shrinking it if it is larger than the buffer.
+    Clamp,
+    /// Restricts the area to the part overlapping the buffer, without moving it.
+    Clip,
+    /// Skips the frame, without advancing the effect, and reports the problem
+    /// through [Effect::out_of_bounds].
+    Error,
+}
+
+/// The area of an effect with [ClampPolicy::Error] extended beyond the buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// The area of the effect.
+    pub area: Rect,
+    /// The area of the buffer.
+    pub buffer_area: Rect,
+}
+
+impl std::fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "effect area {:?} exceeds buffer area {:?}", self.area, self.buffer_area)
+    }
+}
+
+impl std::error::Error for OutOfBounds {}
+
+impl ClampPolicy {
+    /// Applies the policy to `area`, returning the area to process; or `None`
+    /// if the area is out of bounds under [ClampPolicy::Error].
+    fn apply(&self, area: Rect, buffer_area: Rect) -> Option<Rect> {
+        match self {
+            ClampPolicy::Passthrough => Some(area),
+            ClampPolicy::Clamp       => Some(area.clamp(buffer_area)),
+            ClampPolicy::Clip        => Some(area.intersection(buffer_area)),
+            ClampPolicy::Error       => Some(area)
+                .filter(|a| a.is_empty() || buffer_area.union(*a) == buffer_area),
+        }
+    }
 }
 
 impl Effect {
@@ -24,7 +92,17 @@ impl Effect {
     pub fn new<S>(shader: S) -> Self
         where S: Shader + 'static
     {
-        Self { shader: Box::new(shader) }
+        Self {
+            shader: Box::new(shader),
+            reduced_motion: None,
+            last_consumed: Duration::ZERO,
+            clamp_policy: ClampPolicy::default(),
+            out_of_bounds: None,
+            elapsed: Duration::ZERO,
+            marks: Vec::new(),
+            fired: 0,
+            skipped: false,
+        }
     }
 
     /// Creates a new `Effect` with the specified area.
@@ -37,12 +115,13 @@ impl Effect {
     ///
     /// # Example
     /// ```
-    /// use tachyonfx::Effect;
     /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
     ///
-    /// let shader = MyShader::new();
-    /// let effect = Effect::new(shader)
+    /// let effect = fx::fade_to_fg(Color::Red, 500)
     ///     .with_area(Rect::new(0, 0, 10, 10));
+    /// assert_eq!(effect.area(), Some(Rect::new(0, 0, 10, 10)));
     /// ```
     pub fn with_area(&self, area: Rect) -> Self {
         let mut cloned = self.clone();
@@ -60,11 +139,12 @@ impl Effect {
     ///
     /// # Example
     /// ```
-    /// use tachyonfx::{Effect, CellFilter};
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, CellFilter, Shader};
     ///
-    /// let shader = MyShader::new();
-    /// let effect = Effect::new(shader)
+    /// let effect = fx::fade_to_fg(Color::Red, 500)
     ///     .with_cell_selection(CellFilter::Text);
+    /// assert!(matches!(effect.cell_selection(), Some(CellFilter::Text)));
     /// ```
     pub fn with_cell_selection(&self, mode: CellFilter) -> Self {
         let mut cloned = self.clone();
@@ -72,6 +152,165 @@ impl Effect {
         cloned
     }
 
+    /// Creates a new `Effect` which assumes the given width:height aspect ratio of
+    /// cells, in place of the crate-wide default, for geometric computations.
+    ///
+    /// # Example
+    /// ```
+    /// use tachyonfx::fx;
+    ///
+    /// // cells on this terminal are almost square
+    /// let effect = fx::radial_dissolve(500, false).with_cell_aspect(0.8);
+    /// ```
+    pub fn with_cell_aspect(&self, ratio: f32) -> Self {
+        let mut cloned = self.clone();
+        cloned.set_cell_aspect(ratio);
+        cloned
+    }
+
+    /// Creates a new `Effect` which honors, or ignores, a reduced-motion preference,
+    /// in place of the crate-wide default set with [set_reduced_motion](crate::set_reduced_motion).
+    /// The setting applies to this effect only; effects nested within it keep
+    /// following their own setting.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, set_reduced_motion, Shader};
+    ///
+    /// set_reduced_motion(true);
+    ///
+    /// // this translation is exempt, e.g. as it is essential to understanding the UI
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+    /// let mut effect = fx::translate(Some(fx::fade_to_fg(Color::Red, 0)), (2, 0), 1000)
+    ///     .with_reduced_motion(false);
+    /// effect.process(Duration::from_millis(16), &mut buf, Rect::new(0, 0, 2, 1));
+    /// assert_eq!(buf.get(0, 0).fg, Color::Red);
+    /// assert_eq!(buf.get(2, 0).fg, Color::Reset);
+    /// ```
+    pub fn with_reduced_motion(&self, reduced: bool) -> Self {
+        let mut cloned = self.clone();
+        cloned.reduced_motion = Some(reduced);
+        cloned
+    }
+
+    /// Creates a new `Effect` with the given policy for areas extending beyond
+    /// the buffer. Defaults to [ClampPolicy::Passthrough], leaving the area as it is.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, ClampPolicy, Shader};
+    ///
+    /// let screen = Rect::new(0, 0, 4, 1);
+    ///
+    /// // by default, the area is left unchanged
+    /// let mut buf = Buffer::empty(screen);
+    /// let mut effect = fx::fade_to_fg(Color::Red, 100).with_area(Rect::new(2, 0, 2, 1));
+    /// effect.process(Duration::from_millis(100), &mut buf, screen);
+    /// assert_eq!(buf.get(1, 0).fg, Color::Reset);
+    /// assert_eq!(buf.get(2, 0).fg, Color::Red);
+    ///
+    /// // an area extending beyond the buffer is moved inside it when clamping
+    /// let offset = fx::fade_to_fg(Color::Red, 100).with_area(Rect::new(2, 0, 4, 1));
+    /// let mut buf = Buffer::empty(screen);
+    /// let mut effect = offset.with_clamp_policy(ClampPolicy::Clamp);
+    /// effect.process(Duration::from_millis(100), &mut buf, screen);
+    /// assert_eq!(buf.get(0, 0).fg, Color::Red);
+    ///
+    /// let mut buf = Buffer::empty(screen);
+    /// let mut effect = offset.with_clamp_policy(ClampPolicy::Error);
+    /// effect.process(Duration::from_millis(100), &mut buf, screen);
+    /// assert!(effect.out_of_bounds().is_some());
+    /// assert!(effect.running()); // the frame was skipped
+    ///
+    /// let mut effect = effect.with_clamp_policy(ClampPolicy::Clip);
+    /// effect.process(Duration::from_millis(100), &mut buf, screen);
+    /// assert_eq!(effect.out_of_bounds(), None);
+    /// assert_eq!(buf.get(1, 0).fg, Color::Reset);
+    /// assert_eq!(buf.get(2, 0).fg, Color::Red);
+    /// ```
+    pub fn with_clamp_policy(&self, policy: ClampPolicy) -> Self {
+        let mut cloned = self.clone();
+        cloned.clamp_policy = policy;
+        cloned
+    }
+
+    /// Returns the problem with the area of the most recently processed frame,
+    /// if the effect uses [ClampPolicy::Error] and the frame was skipped.
+    pub fn out_of_bounds(&self) -> Option<OutOfBounds> {
+        self.out_of_bounds
+    }
+
+    /// Creates a new `Effect` treating overshooting alpha values, from interpolations
+    /// such as `ElasticOut`, according to `overshoot`. Effects default to
+    /// [OvershootMode::Allow].
+    ///
+    /// The mode is set on the timer of the effect itself, and is honored by all
+    /// effects driven by the alpha of their timer. Effects without a timer of their
+    /// own, such as [sequence](crate::fx::sequence) or [parallel](crate::fx::parallel),
+    /// are left unchanged; set the mode on the nested effects instead. The per-channel
+    /// easing of [FadeColors](crate::fx::FadeColors) is not affected.
+    ///
+    /// # Example
+    /// ```
+    /// use tachyonfx::{fx, Interpolation, OvershootMode};
+    /// use ratatui::style::Color;
+    ///
+    /// // bounces past the target, but never beyond the target color
+    /// let fade = fx::fade_to_fg(Color::Red, (500, Interpolation::ElasticOut))
+    ///     .with_overshoot(OvershootMode::Clamp);
+    /// ```
+    pub fn with_overshoot(&self, overshoot: OvershootMode) -> Self {
+        let mut cloned = self.clone();
+        if let Some(timer) = cloned.timer_mut() {
+            *timer = timer.with_overshoot(overshoot);
+        }
+        cloned
+    }
+
+    /// Checks the composition of the effect for likely mistakes, such as an infinite
+    /// effect in a sequence, which keeps the later stages from ever running. This is
+    /// an opt-in diagnostic and has no effect on processing.
+    ///
+    /// # Returns
+    /// * `Ok(())` if no problems were found, or the warnings, each with the path
+    ///   of child indices leading to the offending effect.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, WarningKind};
+    ///
+    /// let intro = fx::sequence(vec![
+    ///     fx::never_complete(fx::fade_to_fg(Color::Red, 100)),
+    ///     fx::fade_to_fg(Color::Blue, 100),
+    ///     fx::parallel(vec![]),
+    /// ]);
+    ///
+    /// let warnings = intro.validate().unwrap_err();
+    /// assert_eq!(warnings[0].path, vec![0]);
+    /// assert_eq!(warnings[0].kind, WarningKind::BlocksLaterStages);
+    /// assert_eq!(warnings[1].path, vec![2]);
+    /// assert_eq!(warnings[1].kind, WarningKind::EmptyComposite);
+    ///
+    /// let bounded = fx::sequence(vec![
+    ///     fx::with_duration(std::time::Duration::from_millis(200), fx::never_complete(fx::sleep(0))),
+    ///     fx::fade_to_fg(Color::Blue, 100),
+    /// ]);
+    /// assert!(bounded.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<EffectWarning>> {
+        let warnings = self.describe().lint();
+        if warnings.is_empty() { Ok(()) } else { Err(warnings) }
+    }
+
     /// Creates a new `Effect` with the shader's reverse flag toggled.
     ///
     /// # Returns
@@ -81,6 +320,121 @@ impl Effect {
         cloned.reverse();
         cloned
     }
+
+    /// Returns how much of the duration passed to the most recent call to
+    /// `process` was consumed by the effect, i.e. the duration minus any
+    /// overflow. For composite effects, this is the consumption of the
+    /// composite as a whole.
+    ///
+    /// # Returns
+    /// * The consumed duration, or `Duration::ZERO` if the effect has not been processed.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 1, 1);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut effect = fx::fade_to_fg(Color::Red, 100);
+    ///
+    /// effect.process(Duration::from_millis(60), &mut buf, area);
+    /// assert_eq!(effect.last_consumed(), Duration::from_millis(60));
+    ///
+    /// // only 40ms remain; the other 20ms overflow
+    /// effect.process(Duration::from_millis(60), &mut buf, area);
+    /// assert_eq!(effect.last_consumed(), Duration::from_millis(40));
+    /// ```
+    pub fn last_consumed(&self) -> Duration {
+        self.last_consumed
+    }
+
+    /// Creates a new `Effect` which calls `f` once the elapsed time of the effect
+    /// crosses `mark`, e.g. to trigger a sound cue on an animation beat. Marks are
+    /// relative to the start of this effect, and fire in order, at the end of the
+    /// call to `process` crossing them; marks past the end of the effect fire when
+    /// it completes.
+    ///
+    /// Marks fire once per pass over the effect. Each clone of the effect runs
+    /// its own pass, so that the marks of an effect inside [repeat](crate::fx::repeat)
+    /// or [ping_pong](crate::fx::ping_pong) fire on every iteration. Reversing the
+    /// effect, or resetting its timer, starts a new pass. Skipped time does not
+    /// elapse: the marks within it are discarded by [skip_all](Shader::skip_all).
+    ///
+    /// # Example
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let cues = Rc::new(RefCell::new(Vec::new()));
+    /// let cue = |name: &'static str| {
+    ///     let cues = cues.clone();
+    ///     move || cues.borrow_mut().push(name)
+    /// };
+    ///
+    /// let mut effect = fx::fade_to_fg(Color::Red, 100)
+    ///     .at(Duration::from_millis(500), cue("late"))
+    ///     .at(Duration::from_millis(50), cue("beat"))
+    ///     .at(Duration::from_millis(20), cue("intro"));
+    ///
+    /// let area = Rect::new(0, 0, 1, 1);
+    /// let mut buf = Buffer::empty(area);
+    ///
+    /// effect.process(Duration::from_millis(60), &mut buf, area);
+    /// assert_eq!(*cues.borrow(), vec!["intro", "beat"]);
+    ///
+    /// effect.process(Duration::from_millis(60), &mut buf, area);
+    /// assert_eq!(*cues.borrow(), vec!["intro", "beat", "late"]);
+    ///
+    /// // every iteration of a repeat is a new pass
+    /// cues.borrow_mut().clear();
+    /// let beat = fx::fade_to_fg(Color::Red, 100).at(Duration::from_millis(50), cue("beat"));
+    /// let mut effect = fx::repeat_ramp(beat, 3, |_| 1.0);
+    /// (0..4).for_each(|_| { effect.process(Duration::from_millis(80), &mut buf, area); });
+    /// assert_eq!(*cues.borrow(), vec!["beat", "beat", "beat"]);
+    ///
+    /// // resetting the timer rewinds the marks
+    /// cues.borrow_mut().clear();
+    /// let mut effect = fx::fade_to_fg(Color::Red, 100).at(Duration::from_millis(50), cue("beat"));
+    /// effect.process(Duration::from_millis(60), &mut buf, area);
+    /// effect.timer_mut().unwrap().reset();
+    /// effect.process(Duration::from_millis(60), &mut buf, area);
+    /// assert_eq!(*cues.borrow(), vec!["beat", "beat"]);
+    /// ```
+    pub fn at<F: FnMut() + 'static>(&self, mark: Duration, f: F) -> Self {
+        let mut cloned = self.clone();
+        let idx = cloned.marks.partition_point(|m| m.at <= mark);
+        cloned.marks.insert(idx, Mark { at: mark, f: Rc::new(RefCell::new(Box::new(f))) });
+        if idx < cloned.fired {
+            cloned.fired += 1;
+        }
+        cloned
+    }
+
+    /// Fires the marks crossed by the elapsed time.
+    fn fire_marks(&mut self) {
+        let done = self.done();
+        let crossed = self.marks.partition_point(|m| done || m.at <= self.elapsed);
+
+        for mark in self.marks.iter().take(crossed).skip(self.fired) {
+            (mark.f.borrow_mut())();
+        }
+        self.fired = self.fired.max(crossed);
+    }
+
+    /// Starts a new pass over the marks.
+    fn rewind_marks(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.fired = 0;
+    }
 }
 
 /// A filter mode enables effects to operate on specific cells.
@@ -107,6 +461,8 @@ pub enum CellFilter {
     NoneOf(Vec<CellFilter>),
     /// Negates the given filter
     Not(Box<CellFilter>),
+    /// Selects cells at the given positions
+    Positions(Rc<HashSet<Position>>),
 }
 
 pub struct CellSelector {
@@ -133,6 +489,7 @@ impl CellSelector {
             CellFilter::Not(m)        => Self::resolve_area(area, m.as_ref()),
             CellFilter::FgColor(_)    => area,
             CellFilter::BgColor(_)    => area,
+            CellFilter::Positions(_)  => area,
         }
     }
 
@@ -158,6 +515,8 @@ impl CellSelector {
             CellFilter::Not(m)  => self.valid_position(pos, m.as_ref()),
             CellFilter::FgColor(_) => self.inner_area.contains(pos),
             CellFilter::BgColor(_) => self.inner_area.contains(pos),
+            CellFilter::Positions(positions) => self.inner_area.contains(pos)
+                && positions.contains(&pos),
         }
     }
 
@@ -195,14 +554,53 @@ impl CellFilter {
 
 impl Clone for Effect {
     fn clone(&self) -> Self {
-        Self { shader: self.shader.clone_box() }
+        Self {
+            shader: self.shader.clone_box(),
+            reduced_motion: self.reduced_motion,
+            last_consumed: self.last_consumed,
+            clamp_policy: self.clamp_policy,
+            out_of_bounds: self.out_of_bounds,
+            elapsed: self.elapsed,
+            marks: self.marks.clone(),
+            fired: self.fired,
+            skipped: self.skipped,
+        }
     }
 }
 
 impl Shader for Effect {
     fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        if self.reduced_motion.unwrap_or_else(reduced_motion) {
+            if let Some(fallback) = self.shader.reduced() {
+                self.shader = fallback.shader;
+            }
+        }
+
         let area = self.shader.area().unwrap_or(area);
-        self.shader.process(duration, buf, area)
+        let area = match self.clamp_policy.apply(area, buf.area) {
+            Some(area) => area,
+            None => {
+                self.out_of_bounds = Some(OutOfBounds { area, buffer_area: buf.area });
+                self.last_consumed = Duration::ZERO;
+                return None;
+            }
+        };
+
+        self.out_of_bounds = None;
+        if self.shader.timer_mut().is_some_and(|t| !t.started()) {
+            // the timer was reset; as was the effect, as far as its marks are concerned
+            self.rewind_marks();
+        }
+
+        let overflow = self.shader.process(duration, buf, area);
+        self.skipped = false;
+        self.last_consumed = duration.saturating_sub(overflow.unwrap_or_default());
+        self.elapsed += self.last_consumed;
+        if !self.marks.is_empty() {
+            self.fire_marks();
+        }
+
+        overflow
     }
 
     fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator){
@@ -210,7 +608,8 @@ impl Shader for Effect {
     }
 
     fn done(&self) -> bool {
-        self.shader.done()
+        // a skipped effect runs until its final frame has been rendered
+        !self.skipped && self.shader.done()
     }
 
     fn clone_box(&self) -> Box<dyn Shader> {
@@ -230,6 +629,7 @@ impl Shader for Effect {
     }
 
     fn reverse(&mut self) {
+        self.rewind_marks();
         self.shader.reverse()
     }
 
@@ -237,13 +637,99 @@ impl Shader for Effect {
         self.shader.timer_mut()
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        self.shader.timer()
+    }
+
+    fn describe(&self) -> EffectTree {
+        self.shader.describe()
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         self.shader.cell_selection()
     }
+
+    fn reduced(&self) -> Option<Effect> {
+        self.shader.reduced()
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.shader.set_cell_aspect(ratio)
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.shader.is_noop_this_frame(delta)
+    }
+
+    fn skip_current(&mut self) {
+        // the current step of an effect without stages is the effect itself
+        match self.shader.stage_count() {
+            Some(_) => self.shader.skip_current(),
+            None    => self.skip_all(),
+        }
+    }
+
+    fn skip_all(&mut self) {
+        // skipped time never elapses; the marks within it are discarded
+        self.fired = self.marks.len();
+        self.skipped = true;
+        self.shader.skip_all()
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.shader.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.shader.stage_count()
+    }
 }
 
 
-pub trait IntoEffect {
+/// Converts a value into an [Effect].
+///
+/// Implemented for all [Shader]s, and for closures of the shape
+/// `FnMut(Duration, &mut Buffer, Rect) -> Option<Duration>`. Such closures
+/// receive the frame's duration, the buffer and the effect's area, and return
+/// the overflowed duration once the effect is done. Any closure is accepted,
+/// whether it is `Clone` or not; clones of the effect share the closure and its
+/// captured state, as with [effect_fn](crate::fx::effect_fn). The `Marker` type
+/// parameter only serves to keep the two implementations apart and is always
+/// inferred.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{IntoEffect, Shader};
+///
+/// let mut remaining = Duration::from_millis(100);
+/// let mut effect = (move |duration: Duration, buf: &mut Buffer, area: Rect| {
+///     buf.set_string(area.x, area.y, "hi", ratatui::style::Style::default());
+///     remaining = remaining.saturating_sub(duration);
+///     remaining.is_zero().then_some(Duration::ZERO)
+/// }).into_effect();
+///
+/// let area = Rect::new(0, 0, 2, 1);
+/// let mut buf = Buffer::empty(area);
+/// assert_eq!(effect.process(Duration::from_millis(60), &mut buf, area), None);
+/// assert_eq!(buf, Buffer::with_lines(vec!["hi"]));
+/// assert!(effect.process(Duration::from_millis(60), &mut buf, area).is_some());
+/// assert!(effect.done());
+///
+/// // closures capturing state which can't be cloned are accepted too
+/// struct Frames(u32);
+/// let mut frames = Frames(0);
+/// let mut effect = (move |_: Duration, _: &mut Buffer, _: Rect| {
+///     frames.0 += 1;
+///     (frames.0 == 2).then_some(Duration::ZERO)
+/// }).into_effect();
+///
+/// assert_eq!(effect.process(Duration::from_millis(16), &mut buf, area), None);
+/// assert_eq!(effect.process(Duration::from_millis(16), &mut buf, area), Some(Duration::ZERO));
+/// ```
+pub trait IntoEffect<Marker = ()> {
     fn into_effect(self) -> Effect;
 }
 
@@ -253,4 +739,57 @@ impl<S> IntoEffect for S
     fn into_effect(self) -> Effect {
         Effect::new(self)
     }
+}
+
+/// Marker for the [IntoEffect] implementation of closures.
+#[doc(hidden)]
+pub struct ProcessFnMarker;
+
+impl<F> IntoEffect<ProcessFnMarker> for F
+    where F: FnMut(Duration, &mut Buffer, Rect) -> Option<Duration> + 'static
+{
+    fn into_effect(self) -> Effect {
+        Effect::new(ProcessFn { f: Rc::new(RefCell::new(self)), area: None, done: false })
+    }
+}
+
+type ProcessFnSignature = dyn FnMut(Duration, &mut Buffer, Rect) -> Option<Duration>;
+
+/// A shader backed by a closure which is responsible for processing the
+/// entire area and reporting any overflowed duration. Clones share the closure.
+#[derive(Clone)]
+struct ProcessFn {
+    f: Rc<RefCell<ProcessFnSignature>>,
+    area: Option<Rect>,
+    done: bool,
+}
+
+impl Shader for ProcessFn {
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let overflow = self.f.borrow_mut()(duration, buf, area);
+        self.done = overflow.is_some();
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.done
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
 }
\ No newline at end of file
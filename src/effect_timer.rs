@@ -10,12 +10,28 @@ use crate::interpolation::Interpolation;
 /// * `total` - The total duration of the effect.
 /// * `interpolation` - The interpolation method used for the effect.
 /// * `reverse` - A flag indicating whether the effect is reversed.
-#[derive(Clone, Copy, Default)]
+/// * `overshoot` - How alpha values outside of `0.0..=1.0` are treated.
+#[derive(Clone, Copy, Debug, Default)]
 pub struct EffectTimer {
     remaining: Duration,
     total: Duration,
     interpolation: Interpolation,
-    reverse: bool
+    reverse: bool,
+    overshoot: OvershootMode,
+}
+
+/// Determines how an [EffectTimer] treats alpha values outside of `0.0..=1.0`,
+/// as produced by overshooting interpolations such as `ElasticOut` or `BackIn`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OvershootMode {
+    /// Alpha values are passed on as they are; effects handle them as they see fit,
+    /// e.g. movement effects travel past their destination.
+    #[default]
+    Allow,
+    /// Alpha values are clamped to `0.0..=1.0`.
+    Clamp,
+    /// Alpha values past either end wrap around to the other end.
+    Wrap,
 }
 
 impl EffectTimer {
@@ -64,7 +80,8 @@ impl EffectTimer {
             remaining: duration,
             total: duration,
             interpolation,
-            reverse: false
+            reverse: false,
+            overshoot: OvershootMode::default(),
         }
     }
 
@@ -82,6 +99,27 @@ impl EffectTimer {
         Self { reverse: !self.reverse, ..self }
     }
 
+    /// Sets how alpha values outside of `0.0..=1.0` are treated; see [OvershootMode].
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{EffectTimer, Interpolation, OvershootMode};
+    ///
+    /// let mut timer = EffectTimer::from_ms(1000, Interpolation::BackIn);
+    /// timer.process(Duration::from_millis(100));
+    /// assert!(timer.alpha() < 0.0);
+    ///
+    /// let timer = timer.with_overshoot(OvershootMode::Clamp);
+    /// assert_eq!(timer.alpha(), 0.0);
+    ///
+    /// let timer = timer.with_overshoot(OvershootMode::Wrap);
+    /// assert!(timer.alpha() > 0.9);
+    /// ```
+    pub fn with_overshoot(self, overshoot: OvershootMode) -> Self {
+        Self { overshoot, ..self }
+    }
+
     /// Checks if the timer has started.
     ///
     /// # Returns
@@ -109,7 +147,25 @@ impl EffectTimer {
         self.remaining = self.total;
     }
 
-    /// Computes the current alpha value based on the elapsed time and interpolation method.
+    /// Snaps the timer to its end, without any time passing; e.g. when skipping
+    /// an effect.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{EffectTimer, Interpolation};
+    /// let mut timer = EffectTimer::from_ms(1000, Interpolation::Linear);
+    /// timer.finish();
+    /// assert!(timer.done());
+    /// assert_eq!(timer.alpha(), 1.0);
+    /// assert_eq!(timer.process(Duration::ZERO), Some(Duration::ZERO));
+    /// ```
+    pub fn finish(&mut self) {
+        self.remaining = Duration::ZERO;
+    }
+
+    /// Computes the current alpha value based on the elapsed time and interpolation method,
+    /// treating values outside of `0.0..=1.0` according to the timer's [OvershootMode].
     ///
     /// # Returns
     /// * The current alpha value as a `f32`.
@@ -121,16 +177,42 @@ impl EffectTimer {
     /// let alpha = timer.alpha();
     /// ```
     pub fn alpha(&self) -> f32 {
+        if self.total.is_zero() {
+            return 1.0;
+        }
+
+        let alpha = self.interpolation.alpha(self.progress());
+        match self.overshoot {
+            OvershootMode::Allow => alpha,
+            OvershootMode::Clamp => alpha.clamp(0.0, 1.0),
+            OvershootMode::Wrap if (0.0..=1.0).contains(&alpha) => alpha,
+            OvershootMode::Wrap => alpha.rem_euclid(1.0),
+        }
+    }
+
+    /// Computes the linear progress of the timer, before the interpolation method
+    /// is applied. Useful for evaluating the same timer with other interpolations.
+    ///
+    /// # Returns
+    /// * The current progress, ranging from `0.0` to `1.0`; reversed timers run from `1.0` to `0.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{EffectTimer, Interpolation};
+    /// let mut timer = EffectTimer::from_ms(1000, Interpolation::QuadIn);
+    /// timer.process(Duration::from_millis(500));
+    /// assert_eq!(timer.progress(), 0.5);
+    /// assert_eq!(timer.alpha(), Interpolation::QuadIn.alpha(0.5));
+    /// ```
+    pub fn progress(&self) -> f32 {
         let total = self.total.as_secs_f32();
         if total == 0.0 {
             return 1.0;
         }
 
-        let remaining = self.remaining.as_secs_f32();
-        let inv_alpha = remaining / total;
-
-        let a = if self.reverse { inv_alpha } else { 1.0 - inv_alpha };
-        self.interpolation.alpha(a)
+        let inv_alpha = self.remaining.as_secs_f32() / total;
+        if self.reverse { inv_alpha } else { 1.0 - inv_alpha }
     }
 
     /// Processes the timer by reducing the remaining duration by the specified amount.
@@ -140,6 +222,8 @@ impl EffectTimer {
     ///
     /// # Returns
     /// * An `Option` containing the overflow duration if the timer has completed, or `None` if the timer is still running.
+    ///   A timer reaching its end exactly reports completion with a zero overflow, so that
+    ///   the next effect in a sequence starts in the same frame.
     ///
     /// # Example
     /// ```
@@ -148,9 +232,20 @@ impl EffectTimer {
     /// let mut timer = EffectTimer::from_ms(1000, Interpolation::Linear);
     /// let overflow = timer.process(Duration::from_millis(500));
     /// assert!(overflow.is_none());
+    ///
+    /// // completing on the exact boundary
+    /// assert_eq!(timer.process(Duration::from_millis(500)), Some(Duration::ZERO));
+    /// assert!(timer.done());
+    ///
+    /// // overshooting the boundary returns the excess
+    /// let mut timer = EffectTimer::from_ms(1000, Interpolation::Linear);
+    /// (0..3).for_each(|_| { timer.process(Duration::from_millis(333)); });
+    /// assert!(!timer.done());
+    /// assert_eq!(timer.process(Duration::from_millis(16)), Some(Duration::from_millis(15)));
+    /// assert!(timer.done());
     /// ```
     pub fn process(&mut self, duration: Duration) -> Option<Duration> {
-        if self.remaining >= duration {
+        if self.remaining > duration {
             self.remaining -= duration;
             None
         } else {
@@ -160,6 +255,33 @@ impl EffectTimer {
         }
     }
 
+    /// Returns the total duration of the timer.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{EffectTimer, Interpolation};
+    /// let timer = EffectTimer::from_ms(1000, Interpolation::Linear);
+    /// assert_eq!(timer.duration(), Duration::from_secs(1));
+    /// ```
+    pub fn duration(&self) -> Duration {
+        self.total
+    }
+
+    /// Returns the remaining duration of the timer.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{EffectTimer, Interpolation};
+    /// let mut timer = EffectTimer::from_ms(1000, Interpolation::Linear);
+    /// timer.process(Duration::from_millis(400));
+    /// assert_eq!(timer.remaining(), Duration::from_millis(600));
+    /// ```
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+
     /// Checks if the timer has completed.
     ///
     /// # Returns
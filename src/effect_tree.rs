@@ -0,0 +1,199 @@
+use std::fmt;
+
+use crate::{CellFilter, EffectTimer};
+
+/// Effects running their children one after the other.
+const SEQUENCES: [&str; 2] = ["SequentialEffect", "OverlappingSequence"];
+/// Effects running their children at the same time.
+const PARALLELS: [&str; 2] = ["ParallelEffect", "MultiArea"];
+
+/// A snapshot of a running effect and its nested effects, for debugging and
+/// diagnostics; see [Shader::describe](crate::Shader::describe).
+///
+/// The `Display` implementation renders the tree with one effect per line,
+/// indenting nested effects, e.g.:
+///
+/// ```text
+/// SequentialEffect
+///   FadeColors 250ms/1s (25%) [Text]
+///   Sleep 0ns/500ms (0%)
+/// ```
+#[derive(Clone, Debug)]
+pub struct EffectTree {
+    /// The name of the effect, usually the name of its shader type.
+    pub name: &'static str,
+    /// The effect's timer, if it has one.
+    pub timer: Option<EffectTimer>,
+    /// The effect's cell filter, if it has one.
+    pub cell_filter: Option<CellFilter>,
+    /// Whether the effect has completed.
+    pub done: bool,
+    /// Whether the effect runs indefinitely.
+    pub infinite: bool,
+    /// The effects nested within this effect.
+    pub children: Vec<EffectTree>,
+}
+
+impl EffectTree {
+    /// Creates a node named after the shader type `name`, as returned by
+    /// [std::any::type_name]; module paths and generic parameters are stripped.
+    pub fn new(name: &'static str) -> Self {
+        let name = name.split('<').next().unwrap_or(name);
+        let name = name.rsplit("::").next().unwrap_or(name);
+
+        Self {
+            name,
+            timer: None,
+            cell_filter: None,
+            done: false,
+            infinite: false,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_timer(self, timer: Option<EffectTimer>) -> Self {
+        Self { timer, ..self }
+    }
+
+    pub fn with_cell_filter(self, cell_filter: Option<CellFilter>) -> Self {
+        Self { cell_filter, ..self }
+    }
+
+    pub fn with_done(self, done: bool) -> Self {
+        Self { done, ..self }
+    }
+
+    /// Marks the effect as running indefinitely.
+    pub fn infinite(self) -> Self {
+        Self { infinite: true, ..self }
+    }
+
+    pub fn with_children(self, children: Vec<EffectTree>) -> Self {
+        Self { children, ..self }
+    }
+
+    /// Walks the tree and collects warnings about likely mistakes in its
+    /// composition; see [Effect::validate](crate::Effect::validate).
+    pub fn lint(&self) -> Vec<EffectWarning> {
+        let mut warnings = Vec::new();
+        self.lint_node(&mut Vec::new(), false, &mut warnings);
+        warnings
+    }
+
+    fn lint_node(&self, path: &mut Vec<usize>, in_infinite: bool, warnings: &mut Vec<EffectWarning>) {
+        let mut warn = |kind| warnings.push(EffectWarning { path: path.clone(), name: self.name, kind });
+
+        let is_sequence = SEQUENCES.contains(&self.name);
+        if (is_sequence || PARALLELS.contains(&self.name)) && self.children.is_empty() {
+            warn(WarningKind::EmptyComposite);
+        }
+
+        // zero-duration effects are commonly held in place by an infinite parent
+        if !in_infinite && self.timer.is_some_and(|t| t.duration().is_zero()) {
+            warn(WarningKind::ZeroDuration);
+        }
+
+        let last = self.children.len().saturating_sub(1);
+        for (idx, child) in self.children.iter().enumerate() {
+            path.push(idx);
+            if is_sequence && idx < last && child.runs_forever() {
+                warnings.push(EffectWarning {
+                    path: path.clone(),
+                    name: child.name,
+                    kind: WarningKind::BlocksLaterStages,
+                });
+            }
+            child.lint_node(path, in_infinite || self.infinite, warnings);
+            path.pop();
+        }
+    }
+
+    /// Returns whether the effect never completes: it is infinite itself, or
+    /// contains an infinite effect which isn't bounded by a timer.
+    fn runs_forever(&self) -> bool {
+        if self.done {
+            false
+        } else if self.infinite {
+            true
+        } else if SEQUENCES.contains(&self.name) || PARALLELS.contains(&self.name) {
+            self.children.iter().any(EffectTree::runs_forever)
+        } else {
+            self.timer.is_none() && self.children.iter().any(EffectTree::runs_forever)
+        }
+    }
+
+    fn fmt_node(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{:indent$}{}", "", self.name, indent = depth * 2)?;
+
+        if let Some(timer) = self.timer {
+            let total = timer.duration();
+            let elapsed = total.saturating_sub(timer.remaining());
+            let percent = if total.is_zero() {
+                100.0
+            } else {
+                100.0 * elapsed.as_secs_f32() / total.as_secs_f32()
+            };
+            write!(f, " {:?}/{:?} ({:.0}%)", elapsed, total, percent)?;
+        }
+
+        match &self.cell_filter {
+            None | Some(CellFilter::All) => {},
+            Some(filter) => write!(f, " [{:?}]", filter)?,
+        }
+
+        if self.infinite {
+            write!(f, " (infinite)")?;
+        }
+        if self.done {
+            write!(f, " (done)")?;
+        }
+
+        for child in &self.children {
+            writeln!(f)?;
+            child.fmt_node(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for EffectTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_node(f, 0)
+    }
+}
+
+/// A likely mistake in the composition of an effect, as found by
+/// [Effect::validate](crate::Effect::validate).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EffectWarning {
+    /// The indices of the nested effects leading from the root to the offending
+    /// effect; empty for the root itself.
+    pub path: Vec<usize>,
+    /// The name of the offending effect.
+    pub name: &'static str,
+    pub kind: WarningKind,
+}
+
+/// The kind of an [EffectWarning].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarningKind {
+    /// A stage of a sequence never completes, so the stages after it never run.
+    BlocksLaterStages,
+    /// The effect has a zero-duration timer, and completes immediately.
+    ZeroDuration,
+    /// A sequence or parallel effect has no effects to run.
+    EmptyComposite,
+}
+
+impl fmt::Display for EffectWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            WarningKind::BlocksLaterStages => "never completes, blocking the later stages of the sequence",
+            WarningKind::ZeroDuration      => "has a zero duration",
+            WarningKind::EmptyComposite    => "has no effects to run",
+        };
+
+        write!(f, "{} at {:?} {}", self.name, self.path, message)
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Measures the time between frames, producing the `Duration` to pass to
+/// [Shader::process](crate::Shader::process).
+///
+/// Deltas are capped to `max_delta`, so that effects resume where they left off
+/// after a stall instead of jumping ahead; the cap defaults to 250ms. Optionally,
+/// the delta is smoothed by averaging it over the last few frames, which evens out
+/// jitter in the frame rate.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, Instant};
+/// use tachyonfx::FrameClock;
+///
+/// let start = Instant::now();
+/// let mut clock = FrameClock::new()
+///     .with_max_delta(Duration::from_millis(100))
+///     .with_smoothing(2);
+///
+/// assert_eq!(clock.tick_at(start), Duration::ZERO);
+/// assert_eq!(clock.tick_at(start + Duration::from_millis(20)), Duration::from_millis(20));
+/// assert_eq!(clock.tick_at(start + Duration::from_millis(60)), Duration::from_millis(30));
+///
+/// // a stall of 5s is capped to 100ms, and averaged with the previous frame
+/// let after_stall = start + Duration::from_millis(5060);
+/// assert_eq!(clock.tick_at(after_stall), Duration::from_millis(70));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FrameClock {
+    last_tick: Option<Instant>,
+    max_delta: Option<Duration>,
+    smoothing: usize,
+    recent: VecDeque<Duration>,
+}
+
+impl FrameClock {
+    /// Creates a new clock, capping deltas to 250ms and without smoothing.
+    pub fn new() -> Self {
+        Self {
+            last_tick: None,
+            max_delta: Some(Duration::from_millis(250)),
+            smoothing: 1,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Caps the deltas returned by the clock to `max_delta`.
+    pub fn with_max_delta(self, max_delta: Duration) -> Self {
+        Self { max_delta: Some(max_delta), ..self }
+    }
+
+    /// Returns deltas as measured, without capping them.
+    pub fn uncapped(self) -> Self {
+        Self { max_delta: None, ..self }
+    }
+
+    /// Averages the delta over the last `frames` frames; `1` disables smoothing.
+    pub fn with_smoothing(self, frames: usize) -> Self {
+        Self { smoothing: frames.max(1), ..self }
+    }
+
+    /// Records a frame at the current time and returns the delta since the
+    /// previous frame. The first tick returns [Duration::ZERO].
+    pub fn tick(&mut self) -> Duration {
+        self.tick_at(Instant::now())
+    }
+
+    /// Records a frame at `now` and returns the delta since the previous frame.
+    pub fn tick_at(&mut self, now: Instant) -> Duration {
+        let delta = match self.last_tick.replace(now) {
+            Some(last) => now.saturating_duration_since(last),
+            None => return Duration::ZERO,
+        };
+        let delta = self.max_delta.map_or(delta, |max| delta.min(max));
+
+        if self.recent.len() == self.smoothing {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(delta);
+
+        self.recent.iter().sum::<Duration>() / self.recent.len() as u32
+    }
+
+    /// Forgets the previous frame; the next tick returns [Duration::ZERO].
+    /// Useful when resuming after the effects were deliberately paused.
+    pub fn reset(&mut self) {
+        self.last_tick = None;
+        self.recent.clear();
+    }
+}
+
+impl Default for FrameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::Line;
+
+use crate::CellIterator;
+use crate::effect::{CellFilter, Effect};
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// Slides a line of text in the top row of the area from one alignment to
+/// another. Cells vacated by the text are cleared, and text exceeding the width
+/// of the area is clipped.
+#[derive(Clone)]
+pub struct AlignTransition {
+    text: Line<'static>,
+    from: Alignment,
+    to: Alignment,
+    lifetime: EffectTimer,
+    area: Option<Rect>,
+}
+
+impl AlignTransition {
+    pub fn new(
+        text: Line<'static>,
+        from: Alignment,
+        to: Alignment,
+        lifetime: EffectTimer,
+    ) -> Self {
+        Self { text, from, to, lifetime, area: None }
+    }
+}
+
+/// Returns the offset of text of `width` cells, aligned within `max_width` cells.
+fn aligned_offset(alignment: Alignment, width: u16, max_width: u16) -> u16 {
+    let slack = max_width.saturating_sub(width);
+    match alignment {
+        Alignment::Left   => 0,
+        Alignment::Center => slack / 2,
+        Alignment::Right  => slack,
+    }
+}
+
+impl Shader for AlignTransition {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.lifetime.process(duration);
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return overflow;
+        }
+
+        let width = (self.text.width() as u16).min(area.width);
+        let from = aligned_offset(self.from, width, area.width);
+        let to = aligned_offset(self.to, width, area.width);
+
+        let alpha = self.lifetime.alpha();
+        let offset = (from as f32 + (to as f32 - from as f32) * alpha).round() as u16;
+
+        // clears all cells the text passes over, keeping their style
+        let (start, end) = (from.min(to), from.max(to) + width);
+        (area.x + start..area.x + end).for_each(|x| {
+            buf.get_mut(x, area.y).set_char(' ');
+        });
+
+        buf.set_line(area.x + offset, area.y, &self.text, area.width - offset);
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn reduced(&self) -> Option<Effect> {
+        Some(crate::fx::cut(Effect::new(self.clone())))
+    }
+}
@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Rect, Size};
+
+use crate::CellIterator;
+use crate::effect::{CellFilter, Effect};
+use crate::effect_timer::EffectTimer;
+use crate::EffectTree;
+use crate::shader::Shader;
+
+/// The position within a container area to which an anchored area is attached.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Computes an area of the given size, attached to this anchor of the
+    /// `container`, after shrinking the container by `margin`. The resulting
+    /// area is clipped to the shrunk container.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::layout::{Margin, Rect, Size};
+    /// use tachyonfx::fx::Anchor;
+    ///
+    /// let container = Rect::new(0, 0, 80, 24);
+    /// let margin = Margin { horizontal: 2, vertical: 1 };
+    ///
+    /// let area = Anchor::BottomRight.area(container, Size::new(20, 3), margin);
+    /// assert_eq!(area, Rect::new(58, 20, 20, 3));
+    ///
+    /// let area = Anchor::Center.area(container, Size::new(20, 4), Margin::default());
+    /// assert_eq!(area, Rect::new(30, 10, 20, 4));
+    ///
+    /// // margins larger than the container leave no room for the area
+    /// let margin = Margin { horizontal: 40_000, vertical: 40_000 };
+    /// assert!(Anchor::Center.area(container, Size::new(20, 4), margin).is_empty());
+    /// ```
+    pub fn area(&self, container: Rect, size: Size, margin: Margin) -> Rect {
+        let container = Rect {
+            x: container.x.saturating_add(margin.horizontal),
+            y: container.y.saturating_add(margin.vertical),
+            width: container.width.saturating_sub(margin.horizontal.saturating_mul(2)),
+            height: container.height.saturating_sub(margin.vertical.saturating_mul(2)),
+        };
+
+        let width = size.width.min(container.width);
+        let height = size.height.min(container.height);
+        let free_w = container.width - width;
+        let free_h = container.height - height;
+
+        let (dx, dy) = match self {
+            Anchor::TopLeft     => (0,          0),
+            Anchor::Top         => (free_w / 2, 0),
+            Anchor::TopRight    => (free_w,     0),
+            Anchor::Left        => (0,          free_h / 2),
+            Anchor::Center      => (free_w / 2, free_h / 2),
+            Anchor::Right       => (free_w,     free_h / 2),
+            Anchor::BottomLeft  => (0,          free_h),
+            Anchor::Bottom      => (free_w / 2, free_h),
+            Anchor::BottomRight => (free_w,     free_h),
+        };
+
+        Rect::new(container.x.saturating_add(dx), container.y.saturating_add(dy), width, height)
+    }
+}
+
+/// Runs the wrapped effect in an area computed from the container area on
+/// every frame, so that the effect follows its anchor when the layout changes.
+#[derive(Clone)]
+pub struct Anchored {
+    fx: Effect,
+    anchor: Anchor,
+    size: Size,
+    margin: Margin,
+    container: Option<Rect>,
+}
+
+impl Anchored {
+    pub fn new(fx: Effect, anchor: Anchor, size: Size, margin: Margin) -> Self {
+        Self { fx, anchor, size, margin, container: None }
+    }
+}
+
+impl Shader for Anchored {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let container = self.container.unwrap_or(area);
+        let anchored_area = self.anchor.area(container, self.size, self.margin);
+
+        self.fx.set_area(anchored_area);
+        self.fx.process(duration, buf, anchored_area)
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.fx.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.container
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.container = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.fx.set_cell_selection(strategy);
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.fx.set_cell_aspect(ratio);
+    }
+
+    fn reverse(&mut self) {
+        self.fx.reverse();
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(vec![self.fx.describe()])
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.fx.is_noop_this_frame(delta)
+    }
+
+    fn skip_current(&mut self) {
+        self.fx.skip_current();
+    }
+
+    fn skip_all(&mut self) {
+        self.fx.skip_all();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.fx.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.stage_count()
+    }
+}
@@ -0,0 +1,95 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{CellFilter, CellIterator, EffectTimer, Shader};
+
+type RenderSeriesFn = dyn FnMut(&[f64], &mut Buffer, Rect);
+
+/// Interpolates between two data series, rendering the interpolated series
+/// with a user-defined function each frame.
+#[derive(Clone)]
+pub struct AnimateSeries {
+    from: Vec<f64>,
+    to: Vec<f64>,
+    render: Rc<RefCell<RenderSeriesFn>>,
+    timer: EffectTimer,
+    area: Option<Rect>,
+}
+
+impl AnimateSeries {
+    pub fn new<F, T>(from: Vec<f64>, to: Vec<f64>, timer: T, render: F) -> Self
+        where F: FnMut(&[f64], &mut Buffer, Rect) + 'static,
+              T: Into<EffectTimer>
+    {
+        Self {
+            from,
+            to,
+            render: Rc::new(RefCell::new(render)),
+            timer: timer.into(),
+            area: None,
+        }
+    }
+
+    /// Returns the series at `alpha`; the shorter series is padded with zeros.
+    fn series(&self, alpha: f32) -> Vec<f64> {
+        if alpha == 1.0 {
+            return self.to.clone();
+        }
+
+        let value = |series: &[f64], idx: usize| series.get(idx).copied().unwrap_or(0.0);
+        (0..self.from.len().max(self.to.len()))
+            .map(|idx| {
+                let (a, b) = (value(&self.from, idx), value(&self.to, idx));
+                a + (b - a) * alpha as f64
+            })
+            .collect()
+    }
+}
+
+impl Shader for AnimateSeries {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect
+    ) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        let series = self.series(self.timer.alpha());
+
+        self.render.borrow_mut()(&series, buf, area);
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+}
@@ -0,0 +1,79 @@
+use ratatui::layout::Rect;
+
+use crate::CellIterator;
+use crate::color_ext::Luminance;
+use crate::effect::CellFilter;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// Replaces the symbol of each cell with a glyph from a density ramp, picked
+/// by the luminance of the cell's background color scaled by the alpha.
+#[derive(Clone)]
+pub struct AsciiRamp {
+    ramp: Vec<char>,
+    lifetime: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl AsciiRamp {
+    pub fn new(ramp: &str, lifetime: EffectTimer) -> Self {
+        Self {
+            ramp: ramp.chars().collect(),
+            lifetime,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+
+    fn glyph(&self, luminance: f32) -> char {
+        let max_idx = self.ramp.len() - 1;
+        let idx = (luminance.clamp(0.0, 1.0) * max_idx as f32).round() as usize;
+        self.ramp[idx.min(max_idx)]
+    }
+}
+
+impl Shader for AsciiRamp {
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        if self.ramp.is_empty() {
+            return;
+        }
+
+        for (_, cell) in cell_iter {
+            let glyph = self.glyph(cell.bg.luminance() * alpha);
+            cell.set_char(glyph);
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area)
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
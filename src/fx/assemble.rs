@@ -0,0 +1,182 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+use rand::Rng;
+use rand::prelude::{SeedableRng, SmallRng};
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+
+use crate::CellIterator;
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// Assembles the text of the area from glyphs flying in from random positions.
+///
+/// Each glyph starts at a random position within the area, or up to `spread`
+/// cells outside of it, and moves in a straight line to its destination as the
+/// effect progresses. Intermediate positions are rounded to the nearest cell;
+/// glyphs passing over the same cell are drawn in reading order of their
+/// destinations, the last one winning. Only glyphs are moved: the background
+/// stays in place.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use rand::prelude::{SeedableRng, SmallRng};
+/// use ratatui::buffer::Buffer;
+/// use tachyonfx::{Effect, Shader};
+/// use tachyonfx::fx::Assemble;
+///
+/// let title = Buffer::with_lines(vec!["tachyonfx"]);
+/// let area = title.area;
+///
+/// let mut assemble: Effect = Assemble::builder()
+///     .lifetime(1000.into())
+///     .rng(SmallRng::seed_from_u64(7))
+///     .into();
+///
+/// let mut buf = title.clone();
+/// assemble.process(Duration::from_millis(500), &mut buf, area);
+/// assert_ne!(buf, title);
+///
+/// let mut buf = title.clone();
+/// assemble.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf, title);
+/// ```
+#[derive(Builder, Clone)]
+#[builder(pattern = "owned")]
+pub struct Assemble {
+    lifetime: EffectTimer,
+    /// How far outside the area glyphs may start, in cells.
+    #[builder(default = "2")]
+    spread: u16,
+    #[builder(default = "SmallRng::from_entropy()")]
+    rng: SmallRng,
+    #[builder(default)]
+    area: Option<Rect>,
+    #[builder(default)]
+    cell_filter: CellFilter,
+
+    #[builder(setter(skip))]
+    starts: Vec<(f32, f32)>,
+    #[builder(setter(skip))]
+    starts_area: Option<Rect>,
+}
+
+impl Assemble {
+    pub fn builder() -> AssembleBuilder {
+        AssembleBuilder::default()
+    }
+
+    /// Ensures a start position for each of the first `count` glyphs.
+    fn ensure_starts(&mut self, count: usize, area: Rect) {
+        if self.starts_area != Some(area) {
+            self.starts.clear();
+            self.starts_area = Some(area);
+        }
+
+        let spread = self.spread as f32;
+        let x_range = (area.left() as f32 - spread)..(area.right() as f32 + spread);
+        let y_range = (area.top() as f32 - spread)..(area.bottom() as f32 + spread);
+
+        while self.starts.len() < count {
+            let x = self.rng.gen_range(x_range.clone());
+            let y = self.rng.gen_range(y_range.clone());
+            self.starts.push((x, y));
+        }
+    }
+}
+
+impl From<AssembleBuilder> for Effect {
+    fn from(value: AssembleBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+impl Shader for Assemble {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.lifetime.process(duration);
+        let alpha = self.lifetime.alpha();
+        let area = area.intersection(buf.area);
+
+        let mut original = buf.clone();
+        let glyphs: Vec<(Position, Cell)> =
+            CellIterator::new(&mut original, area, Some(self.cell_filter.clone()))
+                .filter(|(_, cell)| cell.symbol() != " ")
+                .map(|(pos, cell)| (pos, cell.clone()))
+                .collect();
+
+        self.ensure_starts(glyphs.len(), area);
+
+        glyphs.iter()
+            .for_each(|(pos, _)| { buf.get_mut(pos.x, pos.y).set_char(' '); });
+
+        for ((dest, glyph), (start_x, start_y)) in glyphs.iter().zip(self.starts.iter()) {
+            let x = (start_x + (dest.x as f32 - start_x) * alpha).round();
+            let y = (start_y + (dest.y as f32 - start_y) * alpha).round();
+
+            let inside = x >= area.left() as f32 && x < area.right() as f32
+                && y >= area.top() as f32 && y < area.bottom() as f32;
+
+            if inside {
+                let cell = buf.get_mut(x as u16, y as u16);
+                cell.set_symbol(glyph.symbol());
+                cell.fg = glyph.fg;
+                cell.modifier = glyph.modifier;
+            }
+        }
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reduced(&self) -> Option<Effect> {
+        let coalesce = crate::fx::coalesce(1000, self.lifetime)
+            .with_cell_selection(self.cell_filter.clone());
+        Some(match self.area {
+            Some(area) => coalesce.with_area(area),
+            None       => coalesce,
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use derive_builder::Builder;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::{CellIterator, ColorMapper, Effect, Interpolatable, IntoEffect};
+use crate::color_ext::ToRgbComponents;
+use crate::effect::CellFilter;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// The color temperature, in kelvin, which is considered neutral white.
+const NEUTRAL_KELVIN: f32 = 6500.0;
+
+/// Shifts the white balance of the foreground and background colors
+/// toward a warmer or cooler color temperature.
+#[derive(Clone, Builder)]
+#[builder(pattern = "owned")]
+pub struct ColorTemperature {
+    /// The temperature change in kelvin, relative to 6500K. Negative values
+    /// shift toward warmer (reddish) tones, positive values toward cooler
+    /// (bluish) tones.
+    kelvin_delta: f32,
+    lifetime: EffectTimer,
+    #[builder(default)]
+    area: Option<Rect>,
+    #[builder(default)]
+    cell_filter: CellFilter,
+}
+
+impl ColorTemperature {
+    pub fn builder() -> ColorTemperatureBuilder {
+        ColorTemperatureBuilder::default()
+    }
+}
+
+impl From<ColorTemperatureBuilder> for Effect {
+    fn from(builder: ColorTemperatureBuilder) -> Self {
+        builder.build().unwrap().into_effect()
+    }
+}
+
+impl Shader for ColorTemperature {
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let kelvin = NEUTRAL_KELVIN.lerp(&(NEUTRAL_KELVIN + self.kelvin_delta), alpha);
+        let balance = white_balance(kelvin);
+
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+
+        for (_, cell) in cell_iter {
+            let fg = fg_mapper.map(cell.fg, alpha, |c| apply_balance(c, balance));
+            let bg = bg_mapper.map(cell.bg, alpha, |c| apply_balance(c, balance));
+
+            cell.set_fg(fg);
+            cell.set_bg(bg);
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> { self.area }
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+fn apply_balance(color: Color, balance: [f32; 3]) -> Color {
+    let (r, g, b) = color.to_rgb();
+    let scale = |c: u8, m: f32| (c as f32 * m).round().clamp(0.0, 255.0) as u8;
+
+    Color::Rgb(scale(r, balance[0]), scale(g, balance[1]), scale(b, balance[2]))
+}
+
+/// Per-channel multipliers which re-balance neutral (6500K) white to
+/// the white point of the given color temperature.
+fn white_balance(kelvin: f32) -> [f32; 3] {
+    let target = kelvin_to_rgb(kelvin);
+    let neutral = kelvin_to_rgb(NEUTRAL_KELVIN);
+
+    [
+        target[0] / neutral[0],
+        target[1] / neutral[1],
+        target[2] / neutral[2],
+    ]
+}
+
+/// Approximates the RGB white point of a black-body radiator at the given
+/// temperature; based on Tanner Helland's curve fit.
+fn kelvin_to_rgb(kelvin: f32) -> [f32; 3] {
+    let t = kelvin.clamp(1000.0, 40000.0) / 100.0;
+
+    let r = if t <= 66.0 {
+        255.0
+    } else {
+        329.69873 * (t - 60.0).powf(-0.13320476)
+    };
+
+    let g = if t <= 66.0 {
+        99.4708 * t.ln() - 161.11957
+    } else {
+        288.12216 * (t - 60.0).powf(-0.07551485)
+    };
+
+    let b = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.51773 * (t - 10.0).ln() - 305.0448
+    };
+
+    [r.clamp(0.0, 255.0), g.clamp(0.0, 255.0), b.clamp(0.0, 255.0)]
+}
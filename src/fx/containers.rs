@@ -1,35 +1,128 @@
 use std::time::Duration;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Rect};
-use crate::{CellIterator, EffectTimer};
+use crate::{BlendMode, CellIterator, EffectTimer};
 use crate::effect::{Effect, CellFilter};
+use crate::EffectTree;
 use crate::shader::Shader;
 
 #[derive(Default, Clone)]
 pub struct SequentialEffect {
     effects: Vec<Effect>,
     current: usize,
+    pending_skip: Option<Skip>,
 }
 
+/// Runs effects in sequence, starting each effect `overlap` before the previous
+/// one completes. See [sequence_overlap](crate::fx::sequence_overlap) for the
+/// timing rules.
+#[derive(Clone)]
+pub struct OverlappingSequence {
+    effects: Vec<Effect>,
+    overlap: Duration,
+    started: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Skip {
+    Current,
+    All,
+}
+
+/// Runs all effects at the same time, in the order given. Without a blend mode,
+/// each effect processes the output of the effects before it, so that later
+/// effects override earlier ones. With a blend mode, every effect processes the
+/// same input, and cells written by more than one effect are blended.
 #[derive(Default, Clone)]
 pub struct ParallelEffect {
     effects: Vec<Effect>,
+    blend_mode: Option<BlendMode>,
 }
 
 impl SequentialEffect {
     pub fn new(effects: Vec<Effect>) -> Self {
-        Self { effects, current: 0 }
+        Self { effects, current: 0, pending_skip: None }
+    }
+
+    /// Renders the final frame of the current effect and advances to the next one.
+    fn finish_current(&mut self, buf: &mut Buffer, area: Rect) {
+        let effect = &mut self.effects[self.current];
+        let effect_area = effect.area().unwrap_or(area);
+
+        effect.skip_all();
+        effect.process(Duration::ZERO, buf, effect_area);
+        self.current += 1;
+    }
+}
+
+impl OverlappingSequence {
+    pub fn new(effects: Vec<Effect>, overlap: Duration) -> Self {
+        Self { effects, overlap, started: 0 }
     }
 }
 
 impl ParallelEffect {
     pub fn new(effects: Vec<Effect>) -> Self {
-        Self { effects }
+        Self { effects, blend_mode: None }
+    }
+
+    pub fn blended(effects: Vec<Effect>, blend_mode: BlendMode) -> Self {
+        Self { effects, blend_mode: Some(blend_mode) }
+    }
+
+    fn process_blended(
+        &mut self,
+        blend_mode: BlendMode,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let original = buf.clone();
+        let mut layer = original.clone();
+        let mut written = vec![false; buf.content.len()];
+        let mut remaining = Some(duration);
+
+        for effect in self.effects.iter_mut().filter(|e| e.running()) {
+            layer.clone_from(&original);
+            let effect_area = effect.area().unwrap_or(area);
+            match effect.process(duration, &mut layer, effect_area) {
+                None => remaining = None,
+                Some(d) if remaining.is_some() => {
+                    remaining = Some(d.min(remaining.unwrap()));
+                }
+                _ => (),
+            }
+
+            let effect_area = effect_area.intersection(buf.area);
+            for y in effect_area.top()..effect_area.bottom() {
+                for x in effect_area.left()..effect_area.right() {
+                    let idx = buf.index_of(x, y);
+                    let layer_cell = &layer.content[idx];
+                    if *layer_cell == original.content[idx] {
+                        continue;
+                    }
+
+                    let cell = &mut buf.content[idx];
+                    *cell = if written[idx] {
+                        blend_mode.blend_cell(cell, layer_cell, 1.0)
+                    } else {
+                        layer_cell.clone()
+                    };
+                    written[idx] = true;
+                }
+            }
+        }
+
+        remaining
     }
 }
 
 impl Shader for ParallelEffect {
     fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        if let Some(blend_mode) = self.blend_mode {
+            return self.process_blended(blend_mode, duration, buf, area);
+        }
+
         let mut remaining = Some(duration);
 
         for effect in self.effects.iter_mut().filter(|e| e.running()) {
@@ -68,6 +161,10 @@ impl Shader for ParallelEffect {
         self.effects.iter_mut().for_each(|e| e.set_cell_selection(strategy.clone()));
     }
 
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.effects.iter_mut().for_each(|e| e.set_cell_aspect(ratio));
+    }
+
     fn reverse(&mut self) {
         self.effects.iter_mut().for_each(Effect::reverse)
     }
@@ -79,6 +176,30 @@ impl Shader for ParallelEffect {
     fn cell_selection(&self) -> Option<CellFilter> {
         None
     }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(self.effects.iter().map(Effect::describe).collect())
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.effects.iter()
+            .filter(|e| e.running())
+            .all(|e| e.is_noop_this_frame(delta))
+    }
+
+    fn skip_current(&mut self) {
+        self.effects.iter_mut()
+            .filter(|e| e.running())
+            .for_each(Effect::skip_current);
+    }
+
+    fn skip_all(&mut self) {
+        self.effects.iter_mut()
+            .filter(|e| e.running())
+            .for_each(Effect::skip_all);
+    }
 }
 
 impl Shader for SequentialEffect {
@@ -88,7 +209,13 @@ impl Shader for SequentialEffect {
         buf: &mut Buffer,
         area: Rect,
     ) -> Option<Duration> {
+        match self.pending_skip.take() {
+            Some(Skip::Current) if !self.done() => self.finish_current(buf, area),
+            Some(Skip::All) => while !self.done() { self.finish_current(buf, area) },
+            _ => (),
+        }
 
+        // the frame's duration is applied in full to the step following a skip
         let mut remaining = Some(duration);
         while remaining.is_some() && !self.done() {
             let effect = &mut self.effects[self.current];
@@ -125,6 +252,129 @@ impl Shader for SequentialEffect {
         self.effects.iter_mut().for_each(|e| e.set_cell_selection(strategy.clone()));
     }
 
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.effects.iter_mut().for_each(|e| e.set_cell_aspect(ratio));
+    }
+
+    fn reverse(&mut self) {
+        self.effects.iter_mut().for_each(Effect::reverse)
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> { None }
+
+    fn cell_selection(&self) -> Option<CellFilter> { None }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(self.effects.iter().map(Effect::describe).collect())
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.pending_skip.is_none()
+            && !self.done()
+            && self.effects[self.current].is_noop_this_frame(delta)
+    }
+
+    fn skip_current(&mut self) {
+        if self.pending_skip.is_none() {
+            self.pending_skip = Some(Skip::Current);
+        }
+    }
+
+    fn skip_all(&mut self) {
+        self.pending_skip = Some(Skip::All);
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        (!self.effects.is_empty()).then(|| self.current.min(self.effects.len() - 1))
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        Some(self.effects.len())
+    }
+}
+
+impl Shader for OverlappingSequence {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        if self.effects.is_empty() {
+            return Some(duration);
+        }
+
+        self.started = self.started.max(1);
+
+        // the part of the frame available to the next, not yet started, effect
+        let mut next_start: Option<Duration> = None;
+        let mut remaining = Some(duration);
+
+        for idx in 0..self.effects.len() {
+            let available = if idx < self.started {
+                duration
+            } else if let Some(d) = next_start.take() {
+                self.started = idx + 1;
+                d
+            } else {
+                break;
+            };
+
+            let overlap = self.overlap;
+            let effect = &mut self.effects[idx];
+            if effect.done() {
+                continue;
+            }
+
+            // time until the effect enters the overlap window, if its duration is known
+            let lead = effect.timer().map(|t| t.remaining().saturating_sub(overlap));
+            let effect_area = effect.area().unwrap_or(area);
+            let overflow = effect.process(available, buf, effect_area);
+
+            match overflow {
+                None => remaining = None,
+                Some(d) => remaining = remaining.map(|r| r.min(d)),
+            }
+
+            if idx + 1 == self.started {
+                next_start = match lead {
+                    Some(lead) if lead <= available => Some(available - lead),
+                    _ => overflow,
+                };
+            }
+        }
+
+        remaining.filter(|_| self.done())
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.started == self.effects.len() && self.effects.iter().all(Effect::done)
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        None
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.effects.iter_mut().for_each(|e| e.set_area(area));
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.effects.iter_mut().for_each(|e| e.set_cell_selection(strategy.clone()));
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.effects.iter_mut().for_each(|e| e.set_cell_aspect(ratio));
+    }
+
     fn reverse(&mut self) {
         self.effects.iter_mut().for_each(Effect::reverse)
     }
@@ -132,4 +382,50 @@ impl Shader for SequentialEffect {
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> { None }
 
     fn cell_selection(&self) -> Option<CellFilter> { None }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(self.effects.iter().map(Effect::describe).collect())
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        let Some(last) = self.started.checked_sub(1).map(|idx| &self.effects[idx]) else {
+            return false;
+        };
+
+        // the next effect must not enter the overlap window during the frame
+        let next_waits = self.started == self.effects.len()
+            || last.timer().is_some_and(|t| t.remaining().saturating_sub(self.overlap) > delta);
+
+        !self.done()
+            && next_waits
+            && self.effects[..self.started].iter()
+                .filter(|e| e.running())
+                .all(|e| e.is_noop_this_frame(delta))
+    }
+
+    fn skip_current(&mut self) {
+        // the started effects run concurrently; the next effect starts right after
+        self.started = self.started.max(1).min(self.effects.len());
+        self.effects[..self.started].iter_mut()
+            .filter(|e| e.running())
+            .for_each(Effect::skip_all);
+    }
+
+    fn skip_all(&mut self) {
+        self.started = self.effects.len();
+        self.effects.iter_mut()
+            .filter(|e| e.running())
+            .for_each(Effect::skip_all);
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        // the most recently started stage
+        (!self.effects.is_empty()).then(|| self.started.saturating_sub(1))
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        Some(self.effects.len())
+    }
 }
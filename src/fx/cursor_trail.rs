@@ -0,0 +1,156 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use derive_builder::Builder;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::{CellIterator, Effect, EffectTimer, Interpolatable, IntoEffect, ParticleLayer};
+use crate::effect::CellFilter;
+use crate::EffectTree;
+use crate::shader::Shader;
+
+type CursorPositionFn = dyn FnMut() -> Option<Position>;
+
+/// Renders a fading trail behind a moving cursor. The cursor position is
+/// polled once per frame; each newly visited cell lights up in the trail
+/// color and decays back to its original colors over the `decay` timer.
+/// While the cursor stays put, no new trail is spawned.
+#[derive(Builder, Clone)]
+#[builder(pattern = "owned")]
+pub struct CursorTrail {
+    #[builder(setter(custom))]
+    position_fn: Rc<RefCell<CursorPositionFn>>,
+    /// The color of the trail, white unless configured.
+    #[builder(default = "Color::White")]
+    trail_color: Color,
+    /// Ramps the intensity of the trail from none to full over its duration, e.g.
+    /// to fade the trail in when entering a mode; reverse it to fade the trail
+    /// out. A zero duration keeps the trail at full intensity throughout.
+    timer: EffectTimer,
+    decay: EffectTimer,
+    /// Replaces the symbol of trail cells with this glyph, if set.
+    #[builder(default, setter(strip_option))]
+    glyph: Option<char>,
+
+    /// The trail, holding up to 16 positions unless configured with `max_len`.
+    #[builder(setter(custom), default = "ParticleLayer::new(16)")]
+    trail: ParticleLayer,
+    #[builder(setter(skip))]
+    last_position: Option<Position>,
+    #[builder(default)]
+    area: Option<Rect>,
+    #[builder(default)]
+    cell_filter: CellFilter,
+}
+
+impl CursorTrail {
+    pub fn builder() -> CursorTrailBuilder {
+        CursorTrailBuilder::default()
+    }
+
+    fn update_trail(&mut self, duration: Duration) {
+        self.trail.update(duration);
+
+        let position = self.position_fn.borrow_mut()();
+        if let Some(pos) = position.filter(|pos| self.last_position != Some(*pos)) {
+            self.trail.spawn(pos, self.decay, ());
+        }
+        self.last_position = position;
+    }
+}
+
+impl CursorTrailBuilder {
+    /// Sets the function which reports the cursor position for the current frame.
+    pub fn position_fn<F>(mut self, f: F) -> Self
+        where F: FnMut() -> Option<Position> + 'static
+    {
+        self.position_fn = Some(Rc::new(RefCell::new(f)));
+        self
+    }
+
+    /// Sets the maximum number of positions tracked by the trail.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.trail = Some(ParticleLayer::new(max_len));
+        self
+    }
+}
+
+impl From<CursorTrailBuilder> for Effect {
+    fn from(value: CursorTrailBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+impl Shader for CursorTrail {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        self.timer.process(duration);
+        self.update_trail(duration);
+        let intensity = self.timer.alpha();
+
+        let selector = self.cell_filter.selector(area);
+
+        self.trail.render(buf, area, |particle, cell| {
+            if !selector.is_valid(particle.position, cell) {
+                return;
+            }
+
+            let a = 1.0 - (1.0 - particle.alpha()) * intensity;
+            if let Some(glyph) = self.glyph {
+                cell.set_char(glyph);
+                cell.set_fg(self.trail_color.lerp(&cell.bg, a));
+            }
+            cell.set_bg(self.trail_color.lerp(&cell.bg, a));
+        });
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool { false }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_timer(self.timer())
+            .with_cell_filter(self.cell_selection())
+            .infinite()
+    }
+}
@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{CellIterator, EffectTimer};
+use crate::effect::{CellFilter, Effect};
+use crate::EffectTree;
+use crate::shader::Shader;
+
+/// Renders the final frame of the wrapped effect for as long as the effect
+/// would have run, in place of animating it.
+#[derive(Clone)]
+pub struct Cut {
+    fx: Effect,
+    lifetime: Option<EffectTimer>,
+}
+
+impl Cut {
+    pub fn new(fx: Effect) -> Self {
+        // the cut is the accessible alternative; it must not be replaced in turn
+        let mut fx = fx.with_reduced_motion(false);
+        let lifetime = fx.timer_mut().copied();
+        Self { fx, lifetime }
+    }
+}
+
+impl Shader for Cut {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        if let Some(timer) = self.fx.timer_mut() {
+            timer.finish();
+        }
+
+        let overflow = self.fx.process(duration, buf, area);
+        match self.lifetime.as_mut() {
+            Some(lifetime) => lifetime.process(duration),
+            None           => overflow,
+        }
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // nothing to do
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.map_or_else(|| self.fx.done(), |t| t.done())
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.fx.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.fx.set_area(area)
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.fx.set_cell_selection(strategy);
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.fx.set_cell_aspect(ratio);
+    }
+
+    fn reverse(&mut self) {
+        self.fx.reverse()
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        self.lifetime.as_mut()
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        self.lifetime
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_timer(self.timer())
+            .with_done(self.done())
+            .with_children(vec![self.fx.describe()])
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.lifetime.is_some_and(|t| t.remaining() > delta)
+            && self.fx.is_noop_this_frame(delta)
+    }
+
+    fn skip_current(&mut self) {
+        self.fx.skip_current();
+    }
+
+    fn skip_all(&mut self) {
+        if let Some(lifetime) = self.lifetime.as_mut() {
+            lifetime.finish();
+        }
+        self.fx.skip_all();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.fx.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.stage_count()
+    }
+}
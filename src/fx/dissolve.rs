@@ -1,45 +1,146 @@
-use rand::{Rng, thread_rng};
+use derive_builder::Builder;
+use rand::Rng;
 use rand::prelude::{SeedableRng, SmallRng};
-use ratatui::layout::Rect;
+use ratatui::layout::{Position, Rect};
 
 use crate::CellIterator;
-use crate::effect::CellFilter;
+use crate::effect::{CellFilter, Effect, IntoEffect};
 use crate::effect_timer::EffectTimer;
+use crate::fx::Direction;
+use crate::rect_ext::{cell_aspect_ratio, radial_distance};
 use crate::shader::Shader;
 
-#[derive(Clone)]
+/// Dissolves cells, replacing them with blank space, as the effect progresses.
+///
+/// By default, the order in which cells dissolve is random. Setting a
+/// `direction` biases the order along an axis, e.g. `Direction::UpToDown`
+/// dissolves the top rows first and the bottom rows last. The `bias` ranges
+/// from `0.0` (fully random) to `1.0` (fully ordered along the direction).
+///
+/// Alternatively, `radial` orders cells by their distance from the center of
+/// the area: `Radial::Outward` dissolves the center first, `Radial::Inward`
+/// the edges first. The `bias` applies in the same way, adding random jitter
+/// to the front.
+///
+/// # Example
+/// ```no_run
+/// use rand::prelude::{SeedableRng, SmallRng};
+/// use tachyonfx::{Effect, Interpolation};
+/// use tachyonfx::fx::{Direction, Dissolve};
+///
+/// // reproducibly dissolves the content, with the bottom rows dissolving last
+/// let falling: Effect = Dissolve::builder()
+///     .lifetime((800, Interpolation::QuadIn).into())
+///     .cycle_len(100)
+///     .direction(Direction::UpToDown)
+///     .bias(0.7)
+///     .rng(SmallRng::seed_from_u64(42))
+///     .into();
+/// ```
+#[derive(Builder, Clone)]
+#[builder(pattern = "owned")]
 pub struct Dissolve {
     lifetime: EffectTimer,
+    /// The number of random cell activation thresholds before they cycle and repeat.
+    cycle_len: usize,
+    #[builder(default, setter(strip_option))]
+    direction: Option<Direction>,
+    /// Orders the dissolve by distance from the center; takes precedence over `direction`.
+    #[builder(default, setter(strip_option))]
+    radial: Option<Radial>,
+    #[builder(default)]
+    bias: f32,
+    #[builder(default = "SmallRng::from_entropy()")]
+    rng: SmallRng,
+    /// The width:height aspect ratio of cells for radial ordering; defaults to
+    /// the crate-wide [cell_aspect_ratio](crate::cell_aspect_ratio).
+    #[builder(default, setter(strip_option))]
+    cell_aspect: Option<f32>,
+
+    #[builder(setter(skip))]
     cyclic_cell_activation: Vec<f32>,
+    #[builder(default)]
     area: Option<Rect>,
+    #[builder(default)]
     cell_filter: CellFilter,
 }
 
+/// The order in which a radial [Dissolve] progresses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Radial {
+    /// From the center towards the edges.
+    Outward,
+    /// From the edges towards the center.
+    Inward,
+}
+
 impl Dissolve {
+    pub fn builder() -> DissolveBuilder {
+        DissolveBuilder::default()
+    }
+
     pub fn new(
         lifetime: EffectTimer,
         cell_cycle: usize,
     ) -> Self {
-        let mut rng = SmallRng::from_rng(thread_rng()).unwrap();
+        Self::builder()
+            .lifetime(lifetime)
+            .cycle_len(cell_cycle)
+            .build()
+            .unwrap()
+    }
 
-        Self {
-            lifetime,
-            cyclic_cell_activation: (0..cell_cycle).map(|_| rng.gen_range(0.0..1.0)).collect(),
-            area: None,
-            cell_filter: CellFilter::All,
+    fn ensure_cell_activation(&mut self) {
+        if self.cyclic_cell_activation.is_empty() {
+            let rng = &mut self.rng;
+            self.cyclic_cell_activation = (0..self.cycle_len.max(1))
+                .map(|_| rng.gen_range(0.0..1.0))
+                .collect();
         }
     }
 
-    fn is_cell_idx_active(&self, idx: usize, a: f32) -> bool {
-        a > self.cyclic_cell_activation[idx % self.cyclic_cell_activation.len()]
+    fn cell_activation(&self, idx: usize, pos: Position, area: Rect) -> f32 {
+        let random = self.cyclic_cell_activation[idx % self.cyclic_cell_activation.len()];
+
+        let aspect = self.cell_aspect.unwrap_or_else(cell_aspect_ratio);
+        let ordered = match (self.radial, self.direction) {
+            (Some(Radial::Outward), _) => radial_distance(pos, area, aspect),
+            (Some(Radial::Inward), _)  => 1.0 - radial_distance(pos, area, aspect),
+            (None, Some(direction))    => axis_position(direction, pos, area),
+            (None, None)               => return random,
+        };
+
+        let bias = self.bias.clamp(0.0, 1.0);
+        random * (1.0 - bias) + ordered * bias
+    }
+}
+
+impl From<DissolveBuilder> for Effect {
+    fn from(value: DissolveBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+/// Returns the position of the cell along the direction, in the range `0.0..1.0`.
+fn axis_position(direction: Direction, pos: Position, area: Rect) -> f32 {
+    let x = (pos.x - area.x) as f32 / area.width.max(1) as f32;
+    let y = (pos.y - area.y) as f32 / area.height.max(1) as f32;
+
+    match direction {
+        Direction::LeftToRight => x,
+        Direction::RightToLeft => 1.0 - x - 1.0 / area.width.max(1) as f32,
+        Direction::UpToDown    => y,
+        Direction::DownToUp    => 1.0 - y - 1.0 / area.height.max(1) as f32,
     }
 }
 
 impl Shader for Dissolve {
 
-    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+    fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator) {
+        self.ensure_cell_activation();
+
         cell_iter.enumerate()
-            .filter(|(idx, _)| self.is_cell_idx_active(*idx, alpha))
+            .filter(|(idx, (pos, _))| alpha > self.cell_activation(*idx, *pos, area))
             .for_each(|(_, (_, c))| { c.set_char(' '); });
     }
 
@@ -63,11 +164,19 @@ impl Shader for Dissolve {
         self.cell_filter = strategy
     }
 
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.cell_aspect = Some(ratio);
+    }
+
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
         Some(&mut self.lifetime)
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.cell_filter.clone())
     }
-}
\ No newline at end of file
+}
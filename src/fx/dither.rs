@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::{CellIterator, EffectTree};
+use crate::color_ext::ToRgbComponents;
+use crate::effect::CellFilter;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// The 16 ANSI colors; [Color::Reset] is left as-is.
+const ANSI_16: [Color; 16] = [
+    Color::Black, Color::Red, Color::Green, Color::Yellow,
+    Color::Blue, Color::Magenta, Color::Cyan, Color::Gray,
+    Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+    Color::LightBlue, Color::LightMagenta, Color::LightCyan, Color::White,
+];
+
+/// Ordered dither thresholds of a 2x2 Bayer matrix, in quarters.
+const BAYER_2X2: [usize; 4] = [0, 2, 3, 1];
+
+/// Downsamples colors to the 16 ANSI colors, approximating the colors in between
+/// by alternating each cell between the two nearest palette colors. The dither
+/// pattern advances through four phases per `cycle`, independent of the frame
+/// rate, so that every cell shows both colors in proportion over a cycle.
+#[derive(Clone)]
+pub struct Dither16 {
+    cycle: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+    /// The palette colors approximating each color seen so far; see [nearest_pair].
+    pairs: HashMap<Color, (Color, Color, f32)>,
+}
+
+impl Dither16 {
+    pub fn new(cycle: EffectTimer) -> Self {
+        Self { cycle, area: None, cell_filter: CellFilter::All, pairs: HashMap::new() }
+    }
+
+    /// Returns the dithered color of the cell at `(x, y)` during phase `phase`.
+    fn dither(&mut self, color: Color, x: u16, y: u16, phase: usize) -> Color {
+        if color == Color::Reset {
+            return color;
+        }
+
+        let (near, far, weight) = *self.pairs.entry(color)
+            .or_insert_with(|| nearest_pair(color));
+        let cell = (x % 2) as usize + 2 * (y % 2) as usize;
+        let threshold = (BAYER_2X2[(cell + phase) % 4] as f32 + 0.5) / 4.0;
+
+        if weight > threshold { far } else { near }
+    }
+}
+
+/// Returns the nearest palette color, the palette color which best approximates
+/// `color` when mixed with it, and the weight of the latter in the mix.
+fn nearest_pair(color: Color) -> (Color, Color, f32) {
+    let rgb = |c: Color| {
+        let (r, g, b) = c.to_rgb();
+        [r as f32, g as f32, b as f32]
+    };
+    let dot = |a: [f32; 3], b: [f32; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let sub = |a: [f32; 3], b: [f32; 3]| [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+
+    let target = rgb(color);
+    let distance = |c: Color| { let d = sub(rgb(c), target); dot(d, d) };
+
+    let near = ANSI_16.into_iter()
+        .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+        .unwrap();
+
+    let origin = rgb(near);
+    let mixes: [(Color, f32, f32); 16] = ANSI_16.map(|c| {
+        if c == near {
+            return (c, 0.0, f32::MAX);
+        }
+
+        // projects the color onto the line between the two palette colors
+        let edge = sub(rgb(c), origin);
+        let weight = (dot(sub(target, origin), edge) / dot(edge, edge)).clamp(0.0, 1.0);
+        let mixed = [0, 1, 2].map(|i| origin[i] + weight * edge[i]);
+        let error = sub(mixed, target);
+        (c, weight, dot(error, error))
+    });
+
+    // several colors may lie on the same line, e.g. the grays; the closest one
+    // dithers with the finest pattern
+    let min_error = mixes.iter().map(|m| m.2).fold(f32::MAX, f32::min);
+    mixes.into_iter()
+        .filter(|m| m.2 <= min_error + 1.0)
+        .min_by(|a, b| distance(a.0).total_cmp(&distance(b.0)))
+        .map(|(far, weight, _)| (near, far, weight))
+        .unwrap()
+}
+
+impl Shader for Dither16 {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        if self.cycle.process(duration).is_some() {
+            self.cycle.reset();
+        }
+        let phase = (self.cycle.alpha() * 4.0) as usize % 4;
+
+        let cell_iter = CellIterator::new(buf, area, Some(self.cell_filter.clone()));
+        cell_iter.for_each(|(pos, cell)| {
+            let fg = self.dither(cell.fg, pos.x, pos.y, phase);
+            let bg = self.dither(cell.bg, pos.x, pos.y, phase);
+            cell.set_fg(fg);
+            cell.set_bg(bg);
+        });
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.cycle)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.cycle)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_timer(self.timer())
+            .with_cell_filter(self.cell_selection())
+            .infinite()
+    }
+}
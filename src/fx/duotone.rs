@@ -0,0 +1,112 @@
+use derive_builder::Builder;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::{CellIterator, ColorMapper, Effect, Interpolatable, IntoEffect};
+use crate::color_ext::Luminance;
+use crate::effect::CellFilter;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// Maps the foreground and background colors to one of two colors, depending
+/// on whether their luminance falls below or above the threshold.
+///
+/// By default, the colors transition from their original values to the duotone
+/// palette over the lifetime of the effect. When any of `to_dark`, `to_light`
+/// or `to_threshold` is set, the duotone applies in full from the start instead,
+/// and the palette and threshold are animated toward the targets.
+#[derive(Clone, Builder)]
+#[builder(pattern = "owned")]
+pub struct Duotone {
+    dark: Color,
+    light: Color,
+    threshold: f32,
+    lifetime: EffectTimer,
+    /// Animates the dark color toward this color over the lifetime of the effect.
+    #[builder(default, setter(strip_option))]
+    to_dark: Option<Color>,
+    /// Animates the light color toward this color over the lifetime of the effect.
+    #[builder(default, setter(strip_option))]
+    to_light: Option<Color>,
+    /// Animates the threshold toward this value over the lifetime of the effect.
+    #[builder(default, setter(strip_option))]
+    to_threshold: Option<f32>,
+    #[builder(default)]
+    area: Option<Rect>,
+    #[builder(default)]
+    cell_filter: CellFilter,
+}
+
+impl Duotone {
+    pub fn builder() -> DuotoneBuilder {
+        DuotoneBuilder::default()
+    }
+
+    fn is_animated(&self) -> bool {
+        self.to_dark.is_some() || self.to_light.is_some() || self.to_threshold.is_some()
+    }
+}
+
+fn duotone_color(color: Color, dark: Color, light: Color, threshold: f32) -> Color {
+    if color.luminance() < threshold {
+        dark
+    } else {
+        light
+    }
+}
+
+impl From<DuotoneBuilder> for Effect {
+    fn from(builder: DuotoneBuilder) -> Self {
+        builder.build().unwrap().into_effect()
+    }
+}
+
+impl Shader for Duotone {
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let dark = self.to_dark.map_or(self.dark, |to| self.dark.lerp(&to, alpha));
+        let light = self.to_light.map_or(self.light, |to| self.light.lerp(&to, alpha));
+        let threshold = self.to_threshold.map_or(self.threshold, |to| self.threshold.lerp(&to, alpha));
+        let mix = if self.is_animated() { 1.0 } else { alpha };
+        let duotone = |c: Color| c.lerp(&duotone_color(c, dark, light, threshold), mix);
+
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+
+        for (_, cell) in cell_iter {
+            let fg = fg_mapper.map(cell.fg, alpha, duotone);
+            let bg = bg_mapper.map(cell.bg, alpha, duotone);
+
+            cell.set_fg(fg);
+            cell.set_bg(bg);
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> { self.area }
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
@@ -2,18 +2,74 @@ use derive_builder::Builder;
 use ratatui::layout::Rect;
 use ratatui::prelude::Color;
 
-use crate::{CellIterator, Interpolatable};
+use crate::{CellIterator, Interpolatable, Interpolation};
+use crate::color_ext::contrast_ratio;
 use crate::color_mapper::ColorMapper;
 use crate::effect::{CellFilter, Effect, IntoEffect};
 use crate::effect_timer::EffectTimer;
 use crate::shader::Shader;
 
+/// The part of a cell's style which is animated by an effect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorChannel {
+    Fg,
+    Bg,
+}
+
+/// Fades the foreground and/or background colors to the given colors.
+///
+/// By default, both colors follow the interpolation of the `lifetime` timer;
+/// `channel_easing` overrides the interpolation of a single channel, e.g. to
+/// let the background lag behind the foreground.
+///
+/// With `min_contrast`, the fade of a cell is held back whenever fading it
+/// further would drop the contrast ratio between its foreground and background
+/// below the minimum; the colors snap to their targets at the end of the fade.
+///
+/// Only the `fg` and `bg` colors of cells are written; modifiers, including
+/// `REVERSED`, are left intact.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{Effect, Interpolation, Shader};
+/// use tachyonfx::fx::{ColorChannel, FadeColors};
+///
+/// let mut fade: Effect = FadeColors::builder()
+///     .fg(Some(Color::Rgb(255, 255, 255)))
+///     .bg(Some(Color::Rgb(255, 255, 255)))
+///     .lifetime((1000, Interpolation::Linear).into())
+///     .channel_easing(ColorChannel::Bg, Interpolation::QuadIn)
+///     .into();
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.get_mut(0, 0).set_fg(Color::Rgb(0, 0, 0)).set_bg(Color::Rgb(0, 0, 0));
+///
+/// fade.process(Duration::from_millis(500), &mut buf, area);
+///
+/// // the background lags behind the foreground
+/// let cell = buf.get(0, 0);
+/// assert_eq!(cell.fg, Color::Rgb(128, 128, 128));
+/// assert_eq!(cell.bg, Color::Rgb(64, 64, 64));
+/// ```
 #[derive(Builder, Clone)]
 #[builder(pattern = "owned")]
 pub struct FadeColors {
     fg: Option<Color>,
     bg: Option<Color>,
     lifetime: EffectTimer,
+    #[builder(default, setter(custom))]
+    fg_easing: Option<Interpolation>,
+    #[builder(default, setter(custom))]
+    bg_easing: Option<Interpolation>,
+    /// The minimum WCAG contrast ratio between the foreground and background
+    /// of each cell, maintained until the fade completes.
+    #[builder(default, setter(strip_option))]
+    min_contrast: Option<f32>,
     #[builder(default)]
     area: Option<Rect>,
     #[builder(default)]
@@ -24,6 +80,55 @@ impl FadeColors {
     pub fn builder() -> FadeColorsBuilder {
         FadeColorsBuilder::default()
     }
+
+    fn channel_alpha(&self, easing: Option<Interpolation>, alpha: f32) -> f32 {
+        easing.map_or(alpha, |easing| easing.alpha(self.lifetime.progress()))
+    }
+
+    /// Fades the colors of a cell, holding back both channels by the same factor
+    /// as far as needed to keep their contrast ratio at or above `min_contrast`.
+    fn readable_colors(
+        &self,
+        fg: Color,
+        bg: Color,
+        fg_alpha: f32,
+        bg_alpha: f32,
+        min_contrast: f32,
+    ) -> (Color, Color) {
+        let fade = |k: f32| {
+            let fg = self.fg.map_or(fg, |to| fg.lerp(&to, fg_alpha * k));
+            let bg = self.bg.map_or(bg, |to| bg.lerp(&to, bg_alpha * k));
+            (fg, bg)
+        };
+        let readable = |k: f32| {
+            let (fg, bg) = fade(k);
+            contrast_ratio(fg, bg) >= min_contrast
+        };
+
+        if readable(1.0) {
+            return fade(1.0);
+        }
+
+        // bisect for the largest readable factor, assuming contrast decreases as the fade progresses
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..8 {
+            let mid = (lo + hi) / 2.0;
+            if readable(mid) { lo = mid } else { hi = mid }
+        }
+
+        fade(lo)
+    }
+}
+
+impl FadeColorsBuilder {
+    /// Overrides the interpolation of the lifetime timer for a single channel.
+    pub fn channel_easing(mut self, channel: ColorChannel, easing: Interpolation) -> Self {
+        match channel {
+            ColorChannel::Fg => self.fg_easing = Some(Some(easing)),
+            ColorChannel::Bg => self.bg_easing = Some(Some(easing)),
+        }
+        self
+    }
 }
 
 impl From<FadeColorsBuilder> for Effect {
@@ -34,17 +139,29 @@ impl From<FadeColorsBuilder> for Effect {
 
 impl Shader for FadeColors {
     fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let fg_alpha = self.channel_alpha(self.fg_easing, alpha);
+        let bg_alpha = self.channel_alpha(self.bg_easing, alpha);
+
         let mut fg_mapper = ColorMapper::default();
         let mut bg_mapper = ColorMapper::default();
 
+        if let Some(min_contrast) = self.min_contrast.filter(|_| fg_alpha.min(bg_alpha) < 1.0) {
+            cell_iter.for_each(|(_, cell)| {
+                let (fg, bg) = self.readable_colors(cell.fg, cell.bg, fg_alpha, bg_alpha, min_contrast);
+                cell.set_fg(fg);
+                cell.set_bg(bg);
+            });
+            return;
+        }
+
         cell_iter.for_each(|(_, cell)| {
             if let Some(fg) = self.fg.as_ref() {
-                let color = fg_mapper.map(cell.fg, alpha, |c| c.lerp(fg, alpha));
+                let color = fg_mapper.map(cell.fg, fg_alpha, |c| c.lerp(fg, fg_alpha));
                 cell.set_fg(color);
             }
 
             if let Some(bg) = self.bg.as_ref() {
-                let color = bg_mapper.map(cell.bg, alpha, |c| c.lerp(bg, alpha));
+                let color = bg_mapper.map(cell.bg, bg_alpha, |c| c.lerp(bg, bg_alpha));
                 cell.set_bg(color);
             }
         });
@@ -74,6 +191,10 @@ impl Shader for FadeColors {
         Some(&mut self.lifetime)
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.cell_filter.clone())
     }
@@ -0,0 +1,11 @@
+/// Returns the blend factor of a cell at the signed `distance`, in cells, from
+/// the boundary of a reveal: `0.0` at or behind the boundary, rising linearly to
+/// `1.0` at `feather` cells in front of it. Without feathering, the transition
+/// is a hard step at the boundary.
+pub(crate) fn feather_alpha(distance: f32, feather: f32) -> f32 {
+    if feather <= 0.0 {
+        if distance > 0.0 { 1.0 } else { 0.0 }
+    } else {
+        (distance / feather).clamp(0.0, 1.0)
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::{CellIterator, EffectTimer, EffectTree, Interpolatable, ParticleLayer};
+use crate::effect::CellFilter;
+use crate::rect_ext::positions;
+use crate::shader::Shader;
+
+/// Highlights cells whose symbol changed since the previous frame, fading the
+/// background from the highlight color back to the cell's own over `fade`.
+#[derive(Clone)]
+pub struct FlashOnChange {
+    highlight: Color,
+    fade: EffectTimer,
+    previous: Option<Buffer>,
+    flashes: ParticleLayer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl FlashOnChange {
+    pub fn new(highlight: Color, fade: EffectTimer) -> Self {
+        Self {
+            highlight,
+            fade,
+            previous: None,
+            // sized to the area on the first frame, which only records the baseline
+            // and thus never spawns flashes
+            flashes: ParticleLayer::new(0),
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+
+    fn snapshot(buf: &Buffer, area: Rect) -> Buffer {
+        let mut snapshot = Buffer::empty(area);
+        positions(area).for_each(|pos| {
+            *snapshot.get_mut(pos.x, pos.y) = buf.get(pos.x, pos.y).clone();
+        });
+
+        snapshot
+    }
+}
+
+impl Shader for FlashOnChange {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let area = area.intersection(buf.area);
+        let current = Self::snapshot(buf, area);
+
+        match self.previous.as_ref() {
+            // new or resized area: reset the baseline
+            Some(previous) if previous.area == area => {
+                self.flashes.update(duration);
+
+                let selector = self.cell_filter.selector(area);
+                let changed: HashSet<Position> = positions(area).filter(|pos| {
+                    let cell = current.get(pos.x, pos.y);
+                    cell.symbol() != previous.get(pos.x, pos.y).symbol()
+                        && selector.is_valid(*pos, cell)
+                }).collect();
+
+                // changed cells restart their flash
+                self.flashes.retain(|flash| !changed.contains(&flash.position));
+                for pos in changed {
+                    self.flashes.spawn(pos, self.fade, ());
+                }
+            },
+            _ => self.flashes = ParticleLayer::new(area.area() as usize),
+        }
+
+        self.flashes.render(buf, area, |flash, cell| {
+            cell.set_bg(self.highlight.lerp(&cell.bg, flash.alpha()));
+        });
+        self.previous = Some(current);
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_cell_filter(self.cell_selection())
+            .infinite()
+    }
+}
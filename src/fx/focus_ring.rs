@@ -0,0 +1,149 @@
+use std::f32::consts::PI;
+use std::time::Duration;
+
+use derive_builder::Builder;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::{CellIterator, EffectTree, Interpolatable};
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// How a [FocusRing] is drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RingStyle {
+    /// The whole ring is highlighted.
+    Solid,
+    /// Dashes of `dash_len` cells, separated by gaps of the same length,
+    /// running around the ring once per cycle.
+    Dashed { dash_len: u16 },
+    /// The whole ring pulses from the original colors to the ring color and back.
+    #[default]
+    Glow,
+}
+
+/// Highlights the edge cells of the area, marking e.g. the focused widget.
+/// Only the foreground colors are changed, so existing borders show through in
+/// the ring color. The animation repeats every `cycle`, and the effect runs
+/// indefinitely.
+#[derive(Builder, Clone)]
+#[builder(pattern = "owned")]
+pub struct FocusRing {
+    color: Color,
+    /// The duration of one animation cycle; the interpolation applies per cycle.
+    cycle: EffectTimer,
+    #[builder(default)]
+    style: RingStyle,
+    #[builder(default)]
+    area: Option<Rect>,
+}
+
+impl FocusRing {
+    pub fn builder() -> FocusRingBuilder {
+        FocusRingBuilder::default()
+    }
+}
+
+impl From<FocusRingBuilder> for Effect {
+    fn from(value: FocusRingBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+/// Returns the edge cells of the area, clockwise from the top-left corner.
+fn perimeter(area: Rect) -> Vec<Position> {
+    if area.is_empty() {
+        return Vec::new();
+    }
+
+    let (left, top) = (area.left(), area.top());
+    let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+
+    let mut cells: Vec<Position> = (left..=right).map(|x| Position::new(x, top)).collect();
+    if bottom > top {
+        cells.extend((top + 1..=bottom).map(|y| Position::new(right, y)));
+        cells.extend((left..right).rev().map(|x| Position::new(x, bottom)));
+    }
+    if right > left && bottom > top {
+        cells.extend((top + 1..bottom).rev().map(|y| Position::new(left, y)));
+    }
+
+    cells
+}
+
+impl Shader for FocusRing {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        if self.cycle.process(duration).is_some() {
+            self.cycle.reset();
+        }
+        let phase = self.cycle.alpha();
+
+        let area = area.intersection(buf.area);
+        let ring = perimeter(area);
+        let len = ring.len();
+
+        for (idx, pos) in ring.into_iter().enumerate() {
+            let cell = buf.get_mut(pos.x, pos.y);
+            match self.style {
+                RingStyle::Solid => { cell.set_fg(self.color); },
+                RingStyle::Dashed { dash_len } => {
+                    let dash_len = dash_len.max(1) as usize;
+                    let shift = (phase * len as f32) as usize % len;
+                    if ((idx + len - shift) % len / dash_len).is_multiple_of(2) {
+                        cell.set_fg(self.color);
+                    }
+                },
+                RingStyle::Glow => {
+                    let a = 0.5 - 0.5 * (2.0 * PI * phase).cos();
+                    let fg = cell.fg.lerp(&self.color, a);
+                    cell.set_fg(fg);
+                },
+            }
+        }
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.cycle)
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_timer(self.timer())
+            .infinite()
+    }
+}
@@ -7,8 +7,11 @@ use rand::prelude::SmallRng;
 use rand::Rng;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
 use crate::{CellIterator, EffectTimer};
+use crate::color_ext::ToRgbComponents;
 use crate::effect::{Effect, CellFilter, IntoEffect};
+use crate::EffectTree;
 use crate::shader::Shader;
 
 
@@ -17,6 +20,9 @@ use crate::shader::Shader;
 enum GlitchType {
     ChangeCase,
     ChangeCharByValue(i8),
+    ShiftCell(i8),
+    SwapColorChannels,
+    DisplaceBlock { len: u8, offset: i8 },
 }
 
 /// A glitch effect that can be applied to a cell.
@@ -35,6 +41,29 @@ impl GlitchCell {
 }
 
 /// applies a glitch effect to random parts of the screen.
+///
+/// Each glitched cell is corrupted in one of several ways, picked at random with
+/// a probability proportional to the intensity of each kind of corruption. A
+/// kind with an intensity of `0.0` is disabled. By default, only glyph
+/// substitution is enabled.
+///
+/// # Example
+/// ```no_run
+/// use rand::prelude::{SeedableRng, SmallRng};
+/// use tachyonfx::Effect;
+/// use tachyonfx::fx::Glitch;
+///
+/// // mostly displaced blocks, with the occasional swapped color channels
+/// let glitch: Effect = Glitch::builder()
+///     .rng(SmallRng::seed_from_u64(7))
+///     .cell_glitch_ratio(0.05)
+///     .action_start_delay_ms(0..500)
+///     .action_ms(100..200)
+///     .glyph_substitution(0.0)
+///     .block_displacement(0.8)
+///     .color_channel_swap(0.2)
+///     .into();
+/// ```
 #[derive(Builder, Clone, Debug)]
 #[builder(pattern = "owned")]
 pub struct Glitch {
@@ -42,6 +71,18 @@ pub struct Glitch {
     action_start_delay_ms: Range<u32>,
     action_ms: Range<u32>,
     rng: SmallRng,
+    /// Replaces glyphs with nearby or differently cased characters.
+    #[builder(default = "1.0")]
+    glyph_substitution: f32,
+    /// Replaces cells with a horizontally nearby cell.
+    #[builder(default)]
+    cell_shift: f32,
+    /// Rotates the RGB channels of the foreground and background colors.
+    #[builder(default)]
+    color_channel_swap: f32,
+    /// Displaces a horizontal run of cells sideways.
+    #[builder(default)]
+    block_displacement: f32,
     #[builder(default)]
     selection: CellFilter,
 
@@ -70,9 +111,12 @@ impl Glitch {
         let current_population = self.glitch_cells.len() as u32;
         if current_population < total_cells {
             for _ in 0..(total_cells - current_population) {
+                let cell_idx = self.rng.gen_range(0..(screen.width * screen.height) as usize);
+                let Some(glitch) = self.glitch_type() else { return };
+
                 GlitchCell::builder()
-                    .cell_idx(self.rng.gen_range(0..(screen.width * screen.height) as usize))
-                    .glitch(self.glitch_type())
+                    .cell_idx(cell_idx)
+                    .glitch(glitch)
                     .glitch_remaining_ms(self.rng.gen_range(self.action_ms.clone()))
                     .presleep_remaining_ms(self.rng.gen_range(self.action_start_delay_ms.clone()))
                     .build()
@@ -94,16 +138,67 @@ impl Glitch {
         cell.glitch_remaining_ms > 0
     }
 
-    fn glitch_type(&mut self) -> GlitchType {
-        let idx: u32 = self.rng.gen();
-        match idx % 2 {
-            0 => GlitchType::ChangeCase,
-            1 => GlitchType::ChangeCharByValue(self.rng.gen_range(-10..10)),
-            _ => unreachable!(),
+    /// Picks a kind of corruption, weighted by intensity; returns `None` if all
+    /// kinds are disabled.
+    fn glitch_type(&mut self) -> Option<GlitchType> {
+        let weights = [
+            self.glyph_substitution,
+            self.cell_shift,
+            self.color_channel_swap,
+            self.block_displacement,
+        ].map(|w| w.max(0.0));
+
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 {
+            return None;
         }
+
+        // only draw from the rng when there is a choice to make
+        let kind = if weights.iter().filter(|w| **w > 0.0).count() == 1 {
+            weights.iter().position(|w| *w > 0.0).unwrap()
+        } else {
+            let mut pick = self.rng.gen_range(0.0..total);
+            weights.iter()
+                .position(|w| { pick -= w; pick < 0.0 })
+                .unwrap_or(weights.len() - 1)
+        };
+
+        let glitch = match kind {
+            0 => {
+                let idx: u32 = self.rng.gen();
+                match idx % 2 {
+                    0 => GlitchType::ChangeCase,
+                    1 => GlitchType::ChangeCharByValue(self.rng.gen_range(-10..10)),
+                    _ => unreachable!(),
+                }
+            },
+            1 => GlitchType::ShiftCell([-2, -1, 1, 2][self.rng.gen_range(0..4)]),
+            2 => GlitchType::SwapColorChannels,
+            _ => GlitchType::DisplaceBlock {
+                len: self.rng.gen_range(2..8),
+                offset: self.rng.gen_range(-4..=4),
+            },
+        };
+
+        Some(glitch)
     }
 }
 
+/// Rotates the RGB channels of the color; `Color::Reset` is left as-is.
+fn rotate_channels(color: Color) -> Color {
+    if color == Color::Reset {
+        return color;
+    }
+
+    let (r, g, b) = color.to_rgb();
+    Color::Rgb(g, b, r)
+}
+
+/// Returns the x-coordinate offset by `dx`, clamped to the area.
+fn offset_x(x: u16, dx: i16, area: Rect) -> u16 {
+    (x as i32 + dx as i32).clamp(area.left() as i32, area.right() as i32 - 1) as u16
+}
+
 impl Shader for Glitch {
     fn process(
         &mut self,
@@ -123,11 +218,31 @@ impl Shader for Glitch {
 
         let selector = self.selection.selector(area);
 
+        // cells displaced by shifts are read from the unglitched buffer
+        let displaces_cells = self.glitch_cells.iter().any(|c| matches!(
+            c.glitch, GlitchType::ShiftCell(_) | GlitchType::DisplaceBlock { .. }
+        ));
+        let original = displaces_cells.then(|| buf.clone());
+
         // apply glitches to buffer
         self.glitch_cells.iter().filter(|c| c.presleep_remaining_ms == 0).for_each(|cell| {
             let x = cell.cell_idx % area.width as usize;
             let y = cell.cell_idx / area.width as usize;
             let pos = Position::new(area.x + x as u16, area.y + y as u16);
+
+            if let GlitchType::DisplaceBlock { len, offset } = cell.glitch {
+                let original = original.as_ref().unwrap();
+                let block_end = (pos.x + len as u16).min(area.right());
+                for x in pos.x..block_end {
+                    let target = Position::new(x, pos.y);
+                    let src_x = offset_x(x, offset as i16, area);
+                    if selector.is_valid(target, buf.get(x, pos.y)) {
+                        *buf.get_mut(x, pos.y) = original.get(src_x, pos.y).clone();
+                    }
+                }
+                return;
+            }
+
             let c  = buf.get_mut(area.x + x as u16, area.y + y as u16);
 
             if !selector.is_valid(pos, c) {
@@ -135,6 +250,14 @@ impl Shader for Glitch {
             }
 
             match cell.glitch {
+                GlitchType::ShiftCell(dx) => {
+                    let src_x = offset_x(pos.x, dx as i16, area);
+                    *c = original.as_ref().unwrap().get(src_x, pos.y).clone();
+                }
+                GlitchType::SwapColorChannels => {
+                    c.fg = rotate_channels(c.fg);
+                    c.bg = rotate_channels(c.bg);
+                }
                 GlitchType::ChangeCase if c.symbol().is_ascii() => {
                     let ch = c.symbol().chars().next().unwrap();
                     c.set_char(if ch.is_ascii_uppercase() {
@@ -192,7 +315,18 @@ impl Shader for Glitch {
 
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> { None }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        // glitches run indefinitely, each cell on its own random schedule
+        None
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.selection.clone())
     }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_cell_filter(self.cell_selection())
+            .infinite()
+    }
 }
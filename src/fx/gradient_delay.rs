@@ -0,0 +1,308 @@
+use std::collections::{BTreeMap, HashSet};
+use std::rc::Rc;
+use std::time::Duration;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+
+use crate::CellIterator;
+use crate::effect::{CellFilter, Effect};
+use crate::effect_timer::EffectTimer;
+use crate::rect_ext::{cell_aspect_ratio, positions, radial_distance};
+use crate::EffectTree;
+use crate::shader::Shader;
+
+/// Delays closer than this are grouped together, as they are indistinguishable
+/// at typical frame rates.
+const DELAY_RESOLUTION_MS: u64 = 16;
+
+/// The axis along which [GradientDelay] spreads out the start of the effect.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientAxis {
+    /// Starts at the left edge and ends at the right edge.
+    LeftToRight,
+    /// Starts at the top edge and ends at the bottom edge.
+    TopToBottom,
+    /// Starts at the center and ends at the corners.
+    Radial,
+}
+
+/// Delays the start of the wrapped effect for each cell, proportionally
+/// to the cell's position along an axis.
+///
+/// Cells with the same delay share a copy of the wrapped effect, restricted to
+/// these cells by its cell selection. Each copy processes the area starting from
+/// the same buffer, and only the cells belonging to the copy are written back.
+/// Effects which ignore cell selections, such as [translate](crate::fx::translate),
+/// see the whole area, and the whole area is restored after each copy.
+#[derive(Clone)]
+pub struct GradientDelay {
+    fx: Effect,
+    axis: GradientAxis,
+    span: Duration,
+    layers: Vec<DelayedLayer>,
+    layer_area: Option<Rect>,
+    cell_aspect: Option<f32>,
+    layer_aspect: f32,
+}
+
+#[derive(Clone)]
+struct DelayedLayer {
+    fx: Effect,
+    delay: Duration,
+    remaining_delay: Duration,
+    cells: Vec<Position>,
+}
+
+impl GradientDelay {
+    pub fn new(fx: Effect, axis: GradientAxis, span: Duration) -> Self {
+        Self {
+            fx,
+            axis,
+            span,
+            layers: Vec::new(),
+            layer_area: None,
+            cell_aspect: None,
+            layer_aspect: 0.0,
+        }
+    }
+
+    /// Returns the delay of the cell, in the range `0.0..=1.0` of the span.
+    fn cell_delay(&self, pos: Position, area: Rect, aspect: f32) -> f32 {
+        let normalize = |v: u16, origin: u16, len: u16| -> f32 {
+            if len > 1 { (v - origin) as f32 / (len - 1) as f32 } else { 0.0 }
+        };
+
+        match self.axis {
+            GradientAxis::LeftToRight => normalize(pos.x, area.x, area.width),
+            GradientAxis::TopToBottom => normalize(pos.y, area.y, area.height),
+            GradientAxis::Radial      => radial_distance(pos, area, aspect),
+        }
+    }
+
+    /// Assigns each cell of the area to the layer with the matching delay,
+    /// creating any missing layers from the wrapped effect.
+    fn assign_layers(&mut self, area: Rect, aspect: f32) {
+        let mut layer_by_key: BTreeMap<u64, usize> = self.layers.iter()
+            .enumerate()
+            .map(|(idx, layer)| (delay_key(layer.delay), idx))
+            .collect();
+
+        self.layers.iter_mut().for_each(|l| l.cells.clear());
+        for pos in positions(area) {
+            let delay = self.span.mul_f32(self.cell_delay(pos, area, aspect));
+            let key = delay_key(delay);
+
+            let idx = *layer_by_key.entry(key).or_insert_with(|| {
+                let delay = Duration::from_millis(key * DELAY_RESOLUTION_MS);
+                self.layers.push(DelayedLayer {
+                    fx: self.fx.clone(),
+                    delay,
+                    remaining_delay: delay,
+                    cells: Vec::new(),
+                });
+                self.layers.len() - 1
+            });
+            self.layers[idx].cells.push(pos);
+        }
+
+        let filter = self.fx.cell_selection();
+        self.layers.iter_mut().for_each(|l| l.restrict(filter.clone()));
+
+        self.layer_area = Some(area);
+        self.layer_aspect = aspect;
+    }
+
+    fn cell_count(&self) -> usize {
+        self.layers.iter().map(|l| l.cells.len()).sum()
+    }
+
+    /// Returns the layer starting first, or the wrapped effect if no layers exist yet.
+    fn leading_fx(&self) -> &Effect {
+        self.layers.iter()
+            .min_by_key(|l| l.delay)
+            .map_or(&self.fx, |l| &l.fx)
+    }
+}
+
+fn delay_key(delay: Duration) -> u64 {
+    delay.as_millis() as u64 / DELAY_RESOLUTION_MS
+}
+
+impl DelayedLayer {
+    /// Restricts the layer's effect to the layer's cells, within `filter`.
+    fn restrict(&mut self, filter: Option<CellFilter>) {
+        let cells = CellFilter::Positions(Rc::new(self.cells.iter().copied().collect::<HashSet<_>>()));
+        let filter = match filter {
+            None | Some(CellFilter::All) => cells,
+            Some(filter)                 => CellFilter::AllOf(vec![filter, cells]),
+        };
+
+        self.fx.set_cell_selection(filter);
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let effect_area = self.fx.area().unwrap_or(area);
+
+        if self.fx.done() {
+            // hold the final frame until all layers are done
+            self.fx.process(Duration::ZERO, buf, effect_area);
+            return Some(duration);
+        }
+
+        let duration = if self.remaining_delay >= duration {
+            self.remaining_delay -= duration;
+            Duration::ZERO
+        } else {
+            let d = duration - self.remaining_delay;
+            self.remaining_delay = Duration::ZERO;
+            d
+        };
+
+        self.fx.process(duration, buf, effect_area)
+            .filter(|_| self.remaining_delay.is_zero())
+    }
+}
+
+impl Shader for GradientDelay {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let aspect = self.cell_aspect.unwrap_or_else(cell_aspect_ratio);
+        if self.layer_area != Some(area) || self.layer_aspect != aspect {
+            self.assign_layers(area, aspect);
+        }
+
+        // each layer processes the buffer in place; its cells are collected and
+        // restored, leaving the buffer as it was for the next layer
+        let bounds = buf.area;
+        let snapshot = self.layers.iter()
+            .any(|l| l.fx.cell_selection().is_none())
+            .then(|| buf.clone());
+        let mut output: Vec<(Position, Cell)> = Vec::with_capacity(self.cell_count());
+        let mut remaining = Some(duration);
+
+        for layer in self.layers.iter_mut() {
+            let start = output.len();
+            output.extend(layer.cells.iter()
+                .filter(|pos| bounds.contains(**pos))
+                .map(|pos| (*pos, buf.get(pos.x, pos.y).clone())));
+
+            match layer.process(duration, buf, area) {
+                None => remaining = None,
+                Some(d) if remaining.is_some() => {
+                    remaining = Some(d.min(remaining.unwrap()));
+                },
+                _ => (),
+            }
+
+            for (pos, cell) in output[start..].iter_mut() {
+                std::mem::swap(cell, buf.get_mut(pos.x, pos.y));
+            }
+
+            // the layer may have written outside its cells
+            if let (Some(snapshot), None) = (snapshot.as_ref(), layer.fx.cell_selection()) {
+                buf.content.iter_mut()
+                    .zip(snapshot.content.iter())
+                    .filter(|(cell, original)| cell != original)
+                    .for_each(|(cell, original)| cell.clone_from(original));
+            }
+        }
+
+        for (pos, cell) in output {
+            *buf.get_mut(pos.x, pos.y) = cell;
+        }
+
+        remaining
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // nothing to do
+    }
+
+    fn done(&self) -> bool {
+        self.layer_area.is_some() && self.layers.iter().all(|l| l.fx.done())
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.fx.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.fx.set_area(area);
+        self.layers.iter_mut().for_each(|l| l.fx.set_area(area));
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.fx.set_cell_selection(strategy.clone());
+        self.layers.iter_mut().for_each(|l| l.restrict(Some(strategy.clone())));
+    }
+
+    fn reverse(&mut self) {
+        self.fx.reverse();
+        self.layers.iter_mut().for_each(|l| l.fx.reverse());
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.cell_aspect = Some(ratio);
+        self.fx.set_cell_aspect(ratio);
+        self.layers.iter_mut().for_each(|l| l.fx.set_cell_aspect(ratio));
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(vec![self.fx.describe()])
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        // layers still waiting keep rendering their first frame, and completed
+        // layers their final frame
+        self.layer_area.is_some() && !self.done() && self.layers.iter().all(|l| {
+            l.fx.done()
+                || l.remaining_delay > delta
+                || (l.remaining_delay.is_zero() && l.fx.is_noop_this_frame(delta))
+        })
+    }
+
+    fn skip_current(&mut self) {
+        self.fx.skip_current();
+        self.layers.iter_mut().for_each(|l| l.fx.skip_current());
+    }
+
+    fn skip_all(&mut self) {
+        self.fx.skip_all();
+        self.layers.iter_mut().for_each(|l| {
+            l.remaining_delay = Duration::ZERO;
+            l.fx.skip_all();
+        });
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.leading_fx().stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.stage_count()
+    }
+}
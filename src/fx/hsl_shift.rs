@@ -78,6 +78,10 @@ impl Shader for HslShift {
         Some(&mut self.lifetime)
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.cell_filter.clone())
     }
@@ -1,40 +1,143 @@
+//! The effects provided by tachyonfx.
+//!
+//! Color effects, such as [fade_to()], [hsl_shift()], [color_temperature()],
+//! [duotone()], [fade_readable()], [sweep_in()] and [term256_colors()], only change
+//! the `fg` and `bg` colors of the cells they process; the symbols and modifiers of
+//! the existing content are left as they are, so color effects can be layered over
+//! any rendered text.
+//!
+//! ```
+//! use std::time::Duration;
+//! use ratatui::buffer::Buffer;
+//! use ratatui::layout::Rect;
+//! use ratatui::style::{Color, Modifier, Style};
+//! use tachyonfx::{fx, Shader};
+//! use tachyonfx::fx::Direction;
+//!
+//! let mut content = Buffer::with_lines(vec!["hello"]);
+//! content.set_style(content.area, Style::default()
+//!     .fg(Color::Rgb(200, 100, 50))
+//!     .bg(Color::Rgb(20, 40, 60))
+//!     .add_modifier(Modifier::BOLD | Modifier::REVERSED));
+//! let area = content.area;
+//!
+//! let gray = Color::Rgb(100, 100, 100);
+//! let effects = vec![
+//!     fx::fade_to(gray, gray, 100),
+//!     fx::fade_from_fg(gray, 100),
+//!     fx::fade_readable(gray, 100, 4.5),
+//!     fx::hsl_shift(Some([120.0, 0.5, 0.1]), Some([-60.0, 0.0, 0.0]), 100),
+//!     fx::color_temperature(-2000.0, 100),
+//!     fx::duotone(Color::Black, Color::White, 0.5, 100),
+//!     fx::sweep_in(Direction::LeftToRight, 5, gray, 100),
+//!     fx::term256_colors(),
+//! ];
+//!
+//! for mut effect in effects {
+//!     let mut buf = content.clone();
+//!     effect.process(Duration::from_millis(50), &mut buf, area);
+//!
+//!     for (cell, original) in buf.content.iter().zip(content.content.iter()) {
+//!         assert_eq!(cell.symbol(), original.symbol());
+//!         assert_eq!(cell.modifier, original.modifier);
+//!     }
+//! }
+//! ```
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::time::Duration;
-use ratatui::layout::Rect;
-use ratatui::style::Color;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Margin, Position, Rect, Size};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use crate::BlendMode;
 use crate::effect::{Effect, IntoEffect};
 use crate::effect_timer::EffectTimer;
+use crate::fx::align_transition::AlignTransition;
+use crate::fx::anchored::Anchored;
+use crate::fx::animate_series::AnimateSeries;
 use crate::fx::ansi256::Ansi256;
+use crate::fx::ascii_ramp::AsciiRamp;
 use crate::fx::consume_tick::ConsumeTick;
-use crate::fx::containers::{ParallelEffect, SequentialEffect};
-use crate::fx::dissolve::Dissolve;
-use crate::fx::fade::FadeColors;
+use crate::fx::cut::Cut;
+use crate::fx::dither::Dither16;
+use crate::fx::containers::{OverlappingSequence, ParallelEffect, SequentialEffect};
+use crate::fx::flash_on_change::FlashOnChange;
+use crate::fx::multi_area::MultiArea;
 use crate::fx::never_complete::NeverComplete;
+use crate::fx::opacity::Opacity;
 use crate::fx::resize::ResizeArea;
-use crate::fx::repeat::Repeat;
+use crate::fx::reveal_title::RevealTitle;
+use crate::fx::repeat::{Repeat, RepeatRamp};
 use crate::fx::sleep::Sleep;
+use crate::fx::snap_to::SnapTo;
+use crate::fx::snapshot::{Capture, Restore};
+use crate::fx::style_transition::StyleTransition;
 use crate::fx::sweep_in::SweepIn;
 use crate::fx::temporary::{IntoTemporaryEffect, TemporaryEffect};
+use crate::fx::wait_for::WaitFor;
 
+pub use anchored::Anchor;
+pub use assemble::Assemble;
+pub use cursor_trail::CursorTrail;
+pub use dissolve::{Dissolve, Radial};
+pub use scroll::Scroll;
+pub use slide::SlideIn;
+pub use fade::{ColorChannel, FadeColors};
+pub use focus_ring::{FocusRing, RingStyle};
 pub use glitch::Glitch;
+pub use gradient_delay::GradientAxis;
+use gradient_delay::GradientDelay;
+use pulse_modifier::PulseModifier;
 use hsl_shift::HslShift;
+use color_temperature::ColorTemperature;
+pub use duotone::Duotone;
+pub use snapshot::SnapshotStore;
+pub use sweep_gradient::SweepGradient;
 pub use sweep_in::Direction;
 
+mod align_transition;
+mod anchored;
+mod animate_series;
 mod ansi256;
+mod ascii_ramp;
+mod assemble;
 mod consume_tick;
 mod containers;
+mod cut;
 mod dissolve;
+mod dither;
 mod fade;
+mod feather;
+mod flash_on_change;
+mod focus_ring;
 mod glitch;
+mod multi_area;
 mod never_complete;
+mod opacity;
 mod ping_pong;
 mod repeat;
 mod resize;
+mod reveal_title;
+mod scroll;
 mod sleep;
+mod style_transition;
+mod sweep_gradient;
 mod sweep_in;
 mod temporary;
 mod translate;
+mod wait_for;
 mod hsl_shift;
 mod shader_fn;
+mod color_temperature;
+mod duotone;
+mod cursor_trail;
+mod slide;
+mod snap_to;
+mod snapshot;
+mod gradient_delay;
+mod pulse_modifier;
 
 use ping_pong::PingPong;
 use shader_fn::ShaderFn;
@@ -78,6 +181,44 @@ where
     ShaderFn::new(f, timer).into_effect()
 }
 
+/// Animates a data update, e.g. of a sparkline or gauge: interpolates between the
+/// `from` and `to` series, eased according to the timer, and calls `render` with
+/// the interpolated series each frame to draw it into the area. Series of differing
+/// lengths are aligned by index, with the shorter series padded with zeros. The
+/// final frame renders `to` exactly.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::prelude::Widget;
+/// use ratatui::widgets::Sparkline;
+/// use tachyonfx::*;
+///
+/// let mut update = fx::animate_series(vec![0.0, 8.0], vec![8.0, 0.0, 8.0], 1000, |series, buf, area| {
+///     let data: Vec<u64> = series.iter().map(|v| v.round() as u64).collect();
+///     Sparkline::default().data(&data).max(8).render(area, buf);
+/// });
+///
+/// let area = Rect::new(0, 0, 3, 1);
+/// let mut buf = Buffer::empty(area);
+/// update.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(vec!["▄▄▄"]));
+///
+/// let mut buf = Buffer::empty(area);
+/// update.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(vec!["█ █"]));
+/// ```
+pub fn animate_series<F, T>(from: Vec<f64>, to: Vec<f64>, timer: T, render: F) -> Effect
+where
+    F: FnMut(&[f64], &mut Buffer, Rect) + 'static,
+    T: Into<EffectTimer>
+{
+    AnimateSeries::new(from, to, timer, render).into_effect()
+}
+
 /// changes the hue, saturation, and lightness of the foreground and background colors.
 pub fn hsl_shift<T: Into<EffectTimer>>(
     hsl_fg_change: Option<[f32; 3]>,
@@ -100,16 +241,479 @@ pub fn hsl_shift_fg<T: Into<EffectTimer>>(
     hsl_shift(Some(hsl_fg_change), None, lifetime)
 }
 
+/// Shifts the white balance of the foreground and background colors toward a
+/// warmer or cooler color temperature. The `kelvin_delta` is relative to neutral
+/// white (6500K): negative values warm the colors up, positive values cool them
+/// down. Indexed colors are resolved to RGB before the shift is applied.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let white = Style::default().bg(Color::Rgb(200, 200, 200));
+/// let shifted = |kelvin_delta| {
+///     let mut buf = Buffer::empty(area);
+///     buf.set_style(area, white);
+///
+///     // dusk: gradually warms up the scene over 2 seconds
+///     let mut fx = fx::color_temperature(kelvin_delta, (2000, Interpolation::SineInOut));
+///     fx.process(Duration::from_secs(2), &mut buf, area);
+///     match buf.get(0, 0).bg {
+///         Color::Rgb(r, g, b) => (r, g, b),
+///         _ => unreachable!(),
+///     }
+/// };
+///
+/// let (r, _, b) = shifted(-3000.0);
+/// assert!(r == 200 && b < 150, "warm: {r}, {b}");
+///
+/// let (r, _, b) = shifted(3000.0);
+/// assert!(r < 170 && b > 200, "cool: {r}, {b}");
+///
+/// assert_eq!(shifted(0.0), (200, 200, 200));
+/// ```
+pub fn color_temperature<T: Into<EffectTimer>>(
+    kelvin_delta: f32,
+    lifetime: T,
+) -> Effect {
+    ColorTemperature::builder()
+        .kelvin_delta(kelvin_delta)
+        .lifetime(lifetime.into())
+        .into()
+}
+
+/// Maps the foreground and background colors to either `dark` or `light`,
+/// depending on whether their perceived luminance is below or above `threshold`
+/// (`0.0..=1.0`). The colors transition from their original values to the
+/// duotone palette over the lifetime of the effect. Use [Duotone::builder()] to
+/// animate the palette or the threshold instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::*;
+///
+/// let (ink, paper) = (Color::Rgb(40, 20, 60), Color::Rgb(250, 200, 80));
+///
+/// let area = Rect::new(0, 0, 2, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.set_style(Rect::new(0, 0, 1, 1), Style::default().bg(Color::Rgb(30, 30, 30)));
+/// buf.set_style(Rect::new(1, 0, 1, 1), Style::default().bg(Color::Rgb(220, 220, 220)));
+///
+/// let mut poster = fx::duotone(ink, paper, 0.5, 500);
+/// poster.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf.get(0, 0).bg, ink);
+/// assert_eq!(buf.get(1, 0).bg, paper);
+/// ```
+///
+/// Animating the threshold, here sweeping it across the full luminance range:
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::*;
+/// use tachyonfx::fx::Duotone;
+///
+/// let (ink, paper) = (Color::Rgb(40, 20, 60), Color::Rgb(250, 200, 80));
+/// let gray = Style::default().bg(Color::Rgb(128, 128, 128));
+/// let area = Rect::new(0, 0, 1, 1);
+///
+/// let mut sweep: Effect = Duotone::builder()
+///     .dark(ink)
+///     .light(paper)
+///     .threshold(0.0)
+///     .to_threshold(1.0)
+///     .lifetime(1000.into())
+///     .into();
+///
+/// let mut frame = |ms| {
+///     let mut buf = Buffer::empty(area);
+///     buf.set_style(area, gray);
+///     sweep.process(Duration::from_millis(ms), &mut buf, area);
+///     buf.get(0, 0).bg
+/// };
+///
+/// assert_eq!(frame(250), paper); // the threshold is below the gray
+/// assert_eq!(frame(750), ink);   // and above it
+/// ```
+pub fn duotone<T: Into<EffectTimer>, C: Into<Color>>(
+    dark: C,
+    light: C,
+    threshold: f32,
+    lifetime: T,
+) -> Effect {
+    Duotone::builder()
+        .dark(dark.into())
+        .light(light.into())
+        .threshold(threshold)
+        .lifetime(lifetime.into())
+        .into()
+}
+
+/// Renders the background colors as text: each cell's symbol is replaced by a glyph
+/// from the density `ramp`, ordered from darkest to brightest, according to the
+/// perceived luminance of the cell's background. The mapping is animated in over
+/// the lifetime of the effect: all cells start out as the first glyph of the ramp
+/// and reach their full brightness when the effect completes.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 3, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.get_mut(0, 0).set_bg(Color::Rgb(0, 0, 0));
+/// buf.get_mut(1, 0).set_bg(Color::Rgb(128, 128, 128));
+/// buf.get_mut(2, 0).set_bg(Color::Rgb(255, 255, 255));
+///
+/// let mut rendering = fx::ascii_ramp(" .:-=+*#%@", 1000);
+///
+/// rendering.process(Duration::ZERO, &mut buf, area);
+/// assert!(buf.content.iter().all(|c| c.symbol() == " "));
+///
+/// rendering.process(Duration::from_millis(1000), &mut buf, area);
+/// assert_eq!(buf.get(0, 0).symbol(), " ");
+/// assert_eq!(buf.get(1, 0).symbol(), "+");
+/// assert_eq!(buf.get(2, 0).symbol(), "@");
+/// ```
+pub fn ascii_ramp<T: Into<EffectTimer>>(ramp: &str, lifetime: T) -> Effect {
+    AsciiRamp::new(ramp, lifetime.into()).into_effect()
+}
+
+/// Slides a line of text from one alignment to another, e.g. when a header
+/// changes from left-aligned to centered. The text is drawn in the top row of the
+/// area, with its horizontal offset rounded to whole cells and eased by the
+/// timer. Cells vacated by the text are cleared, and text wider than the area is
+/// clipped. Under a reduced-motion preference, the text cuts to its new alignment
+/// instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Alignment, Rect};
+/// use ratatui::text::Line;
+/// use tachyonfx::{fx, Shader};
+///
+/// let area = Rect::new(0, 0, 9, 1);
+/// let mut fx = fx::align_transition(Line::from("abc"), Alignment::Left, Alignment::Right, 1000);
+///
+/// let mut buf = Buffer::with_lines(vec!["abc      "]);
+/// fx.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(vec!["   abc   "]));
+///
+/// fx.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(vec!["      abc"]));
+/// ```
+pub fn align_transition<T: Into<EffectTimer>>(
+    text: Line<'static>,
+    from: Alignment,
+    to: Alignment,
+    lifetime: T,
+) -> Effect {
+    AlignTransition::new(text, from, to, lifetime.into()).into_effect()
+}
+
+/// Types out the title of a bordered block, one character at a time, in the top
+/// border of the area. The title is placed according to its alignment, like a
+/// `Block` title; cells of the border not yet covered by the title are left as
+/// they are. Use it as a stage after the frame of a window has been drawn.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::prelude::Widget;
+/// use ratatui::text::Line;
+/// use ratatui::widgets::{Block, Borders};
+/// use tachyonfx::{fx, Shader};
+///
+/// let area = Rect::new(0, 0, 10, 3);
+/// let mut fx = fx::reveal_title(Line::from("hello"), 1000);
+///
+/// let mut buf = Buffer::empty(area);
+/// Block::default().borders(Borders::ALL).render(area, &mut buf);
+/// fx.process(Duration::from_millis(600), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(vec![
+///     "┌hel─────┐",
+///     "│        │",
+///     "└────────┘",
+/// ]));
+/// ```
+pub fn reveal_title<T: Into<EffectTimer>>(title: Line<'static>, lifetime: T) -> Effect {
+    RevealTitle::new(title, lifetime.into()).into_effect()
+}
+
+/// Draws an animated ring of `color` along the edge cells of the area, e.g. to mark
+/// the focused widget. Only the foreground colors of the edge cells are changed, so
+/// existing borders are highlighted in place. By default, the ring glows, pulsing
+/// once per `cycle`; see [FocusRing::builder()] for solid and dashed rings. The
+/// effect runs indefinitely.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{Effect, Shader};
+/// use tachyonfx::fx::{FocusRing, RingStyle};
+///
+/// let area = Rect::new(0, 0, 4, 3);
+/// let mut buf = Buffer::empty(area);
+///
+/// let mut ring: Effect = FocusRing::builder()
+///     .color(Color::Yellow)
+///     .cycle(1000.into())
+///     .style(RingStyle::Dashed { dash_len: 2 })
+///     .into();
+/// ring.process(Duration::ZERO, &mut buf, area);
+///
+/// let highlighted = |x, y| buf.get(x, y).fg == Color::Yellow;
+/// assert!(highlighted(0, 0) && highlighted(1, 0));
+/// assert!(!highlighted(2, 0) && !highlighted(3, 0));
+/// assert!(!highlighted(1, 1)); // not an edge cell
+/// assert!(!ring.done());
+/// ```
+pub fn focus_ring<T: Into<EffectTimer>, C: Into<Color>>(color: C, cycle: T) -> Effect {
+    FocusRing::builder()
+        .color(color.into())
+        .cycle(cycle.into())
+        .into()
+}
+
+/// Pulses a style modifier, such as `BOLD` or `REVERSED`, on the selected cells:
+/// the modifier is added during the first half of the effect's lifetime ("on")
+/// and removed during the second half ("off"). Since it only relies on terminal
+/// modifiers, it works equally well on terminals with limited color support.
+/// Combine with [repeating()] or [ping_pong()] for continuous blinking.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Modifier;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+/// let mut blink = fx::pulse_modifier(Modifier::REVERSED, 1000);
+///
+/// blink.process(Duration::from_millis(250), &mut buf, area);
+/// assert!(buf.get(0, 0).modifier.contains(Modifier::REVERSED));
+///
+/// blink.process(Duration::from_millis(500), &mut buf, area);
+/// assert!(!buf.get(0, 0).modifier.contains(Modifier::REVERSED));
+/// ```
+///
+/// Cells which carry the modifier already, e.g. a selected row, keep it:
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Modifier, Style};
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 2, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.set_style(Rect::new(0, 0, 1, 1), Style::default().add_modifier(Modifier::BOLD));
+///
+/// let mut blink = fx::pulse_modifier(Modifier::BOLD, 1000);
+/// blink.process(Duration::from_millis(250), &mut buf, area);
+/// assert!(buf.get(1, 0).modifier.contains(Modifier::BOLD));
+///
+/// blink.process(Duration::from_millis(1000), &mut buf, area);
+/// assert!(blink.done());
+/// assert!(buf.get(0, 0).modifier.contains(Modifier::BOLD));
+/// assert!(!buf.get(1, 0).modifier.contains(Modifier::BOLD));
+/// ```
+pub fn pulse_modifier<T: Into<EffectTimer>>(modifier: Modifier, lifetime: T) -> Effect {
+    PulseModifier::new(modifier, lifetime.into()).into_effect()
+}
+
+/// Transitions the style of the cells from `from` to `to`. The foreground,
+/// background and underline colors are interpolated, with colors unset in a
+/// style taken from the cell itself. Modifiers are discrete: those of `from`
+/// apply during the first half of the transition, and those of `to` replace them
+/// from the halfway point onwards. Symbols are left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Modifier, Style};
+/// use tachyonfx::{fx, Shader};
+///
+/// let idle = Style::new().fg(Color::Rgb(0, 0, 0)).add_modifier(Modifier::DIM);
+/// let active = Style::new().fg(Color::Rgb(255, 255, 255)).add_modifier(Modifier::BOLD);
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut effect = fx::style_transition(idle, active, 1000);
+///
+/// let mut buf = Buffer::with_lines(vec!["x"]);
+/// effect.process(Duration::from_millis(499), &mut buf, area);
+/// assert_eq!(buf.get(0, 0).modifier, Modifier::DIM);
+///
+/// // the same buffer, processed across the halfway point
+/// effect.process(Duration::from_millis(1), &mut buf, area);
+/// assert_eq!(buf.get(0, 0).modifier, Modifier::BOLD);
+/// assert_eq!(buf.get(0, 0).fg, Color::Rgb(128, 128, 128));
+/// assert_eq!(buf.get(0, 0).symbol(), "x");
+///
+/// effect.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf.get(0, 0).fg, Color::Rgb(255, 255, 255));
+/// assert_eq!(buf.get(0, 0).modifier, Modifier::BOLD);
+/// ```
+pub fn style_transition<T: Into<EffectTimer>>(from: Style, to: Style, lifetime: T) -> Effect {
+    StyleTransition::new(from, to, lifetime.into()).into_effect()
+}
+
+/// Highlights cells whose symbol changed since the previous frame, e.g. updated
+/// values in a table. Each changed cell flashes in the `highlight` background
+/// color, fading back to its own background over `fade`; a cell changing again
+/// restarts its flash. The first frame, and any frame after the area is
+/// resized, only records the baseline. Runs indefinitely.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Shader};
+///
+/// let mut effect = fx::flash_on_change(Color::Yellow, 300);
+/// let frame = Duration::from_millis(16);
+///
+/// let mut buf = Buffer::with_lines(vec!["42.0"]);
+/// let area = buf.area;
+/// effect.process(frame, &mut buf, area);
+///
+/// let mut buf = Buffer::with_lines(vec!["42.5"]);
+/// effect.process(frame, &mut buf, area);
+///
+/// assert_eq!(buf.get(3, 0).bg, Color::Yellow);
+/// assert_eq!(buf.get(0, 0).bg, Color::Reset);
+/// ```
+pub fn flash_on_change<T: Into<EffectTimer>, C: Into<Color>>(highlight: C, fade: T) -> Effect {
+    FlashOnChange::new(highlight.into(), fade.into()).into_effect()
+}
+
 /// Returns an effect that downsamples to 256 color mode.
 pub fn term256_colors() -> Effect {
     Ansi256::default().into_effect()
 }
 
+/// Returns an effect that downsamples to the 16 ANSI colors, dithering over time.
+///
+/// Colors between two palette colors alternate between them, with each cell
+/// cycling through four dither phases per `cycle`. Over a cycle, every cell shows
+/// the two colors in proportion to how close the original color is to each,
+/// making gradients and fades look smoother than snapping to the nearest color.
+/// The phases advance with the elapsed time, so the pattern changes at the same
+/// rate regardless of the frame rate. The effect runs indefinitely.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::{fx, Shader};
+///
+/// let area = Rect::new(0, 0, 2, 2);
+/// let halfway = Style::default().bg(Color::Rgb(32, 32, 32));
+///
+/// let mut effect = fx::dither_to_16(400);
+/// let mut shown = vec![];
+/// for frame in [0, 100, 100, 100] {
+///     let mut buf = Buffer::empty(area);
+///     buf.set_style(area, halfway);
+///     effect.process(Duration::from_millis(frame), &mut buf, area);
+///
+///     // half of the cells show the lighter color
+///     let lighter = buf.content.iter().filter(|c| c.bg == Color::DarkGray).count();
+///     assert_eq!(lighter, 2);
+///     shown.push(buf.get(0, 0).bg);
+/// }
+///
+/// // and each cell shows it for half of the cycle
+/// assert_eq!(shown.iter().filter(|c| **c == Color::DarkGray).count(), 2);
+/// assert!(shown.iter().all(|c| [Color::Black, Color::DarkGray].contains(c)));
+/// ```
+pub fn dither_to_16<T: Into<EffectTimer>>(cycle: T) -> Effect {
+    Dither16::new(cycle.into()).into_effect()
+}
+
 /// Repeat the effect indefinitely or for a specified number of times or duration.
 pub fn repeat(effect: Effect, mode: repeat::RepeatMode) -> Effect {
     Repeat::new(effect, mode).into_effect()
 }
 
+/// Repeats the effect `count` times, with the duration of each iteration scaled
+/// by the factor `time_curve` returns for the iteration's index, starting at `0`.
+/// Factors below `1.0` speed up an iteration, and factors above slow it down,
+/// e.g. for pulses which accelerate like a quickening heartbeat. Time left over
+/// from a completed iteration carries over into the next.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Shader};
+///
+/// // 400ms, 200ms, 100ms
+/// let mut heartbeat = fx::repeat_ramp(
+///     fx::fade_from_fg(Color::Red, 400),
+///     3,
+///     |i| 0.5f32.powi(i as i32),
+/// );
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// assert_eq!(heartbeat.process(Duration::from_millis(650), &mut buf, area), None);
+/// assert!(heartbeat.running());
+///
+/// let overflow = heartbeat.process(Duration::from_millis(100), &mut buf, area);
+/// assert_eq!(overflow, Some(Duration::from_millis(50)));
+/// assert!(heartbeat.done());
+/// ```
+pub fn repeat_ramp<F>(effect: Effect, count: u32, time_curve: F) -> Effect
+    where F: Fn(u32) -> f32 + 'static
+{
+    RepeatRamp::new(effect, count, time_curve).into_effect()
+}
+
 /// plays the effect forwards and then backwards.
 pub fn ping_pong(effect: Effect) -> Effect {
     PingPong::new(effect).into_effect()
@@ -147,6 +751,47 @@ pub fn sweep_in<T: Into<EffectTimer>, C: Into<Color>>(
         .into_effect()
 }
 
+/// Reveals the content behind a band of `edge_color`, `width` cells thick, which
+/// sweeps across the area in the given direction. Content ahead of the band is
+/// hidden, and the band fades from `edge_color` at its leading edge into the
+/// content's own colors at its trailing edge, making the reveal boundary glow.
+/// See [SweepGradient::builder()] for feathering the leading edge.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Shader};
+/// use tachyonfx::fx::Direction;
+///
+/// let mut buf = Buffer::with_lines(vec!["abcdefgh"]);
+/// let area = buf.area;
+///
+/// let mut reveal = fx::sweep_gradient(Direction::LeftToRight, Color::White, 2, 1000);
+/// reveal.process(Duration::from_millis(500), &mut buf, area);
+///
+/// assert_eq!(buf.get(0, 0).symbol(), "a"); // revealed
+/// assert_ne!(buf.get(4, 0).fg, Color::Reset); // within the glowing band
+/// assert_eq!(buf.get(7, 0).symbol(), " "); // not yet revealed
+/// ```
+pub fn sweep_gradient<T: Into<EffectTimer>, C: Into<Color>>(
+    direction: Direction,
+    edge_color: C,
+    width: u16,
+    lifetime: T,
+) -> Effect {
+    SweepGradient::builder()
+        .direction(direction)
+        .edge_color(edge_color.into())
+        .width(width)
+        .lifetime(lifetime.into())
+        .into()
+}
+
+/// Moves the area of the wrapped effect by `translate_by` cells. Under a
+/// reduced-motion preference, the area cuts to its destination instead.
 pub fn translate<T: Into<EffectTimer>>(
     fx: Option<Effect>,
     translate_by: (i16, i16),
@@ -156,7 +801,8 @@ pub fn translate<T: Into<EffectTimer>>(
 }
 
 /// An effect that resizes the area of the wrapped effect to the specified
-/// dimensions. The effect will be rendered within the resized area.
+/// dimensions. The effect will be rendered within the resized area. Under a
+/// reduced-motion preference, the area cuts to its full size instead.
 pub fn resize_area<T: Into<EffectTimer>>(
     fx: Option<Effect>,
     initial_w: u16,
@@ -166,26 +812,380 @@ pub fn resize_area<T: Into<EffectTimer>>(
     ResizeArea::new(fx, initial_w, initial_h, lifetime.into()).into_effect()
 }
 
+/// Slides the content in from one side, moving in the given direction. The
+/// content overshoots its resting position by a couple of cells before settling
+/// back, ending exactly at rest. Exposed regions are left blank. See
+/// [SlideIn::builder()] for configuring the overshoot and the fill color.
+/// Under a reduced-motion preference, the content fades in from the fill color
+/// in place instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use tachyonfx::{fx, Shader};
+/// use tachyonfx::fx::Direction;
+///
+/// let content = Buffer::with_lines(vec!["abcdefgh"]);
+/// let area = content.area;
+/// let mut effect = fx::slide_in_bounce(Direction::LeftToRight, 1000);
+///
+/// let mut buf = content.clone();
+/// effect.process(Duration::from_millis(700), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(vec!["  abcdef"])); // overshooting by 2 cells
+///
+/// let mut buf = content.clone();
+/// effect.process(Duration::from_millis(300), &mut buf, area);
+/// assert_eq!(buf, content); // settled
+/// ```
+pub fn slide_in_bounce<T: Into<EffectTimer>>(direction: Direction, lifetime: T) -> Effect {
+    SlideIn::builder()
+        .direction(direction)
+        .lifetime(lifetime.into())
+        .into()
+}
+
+/// Scrolls the content of the area by `distance` cells in the given direction,
+/// e.g. to animate a jump in a list. Unlike [translate()], the content already in
+/// the area is moved in place. Vacated cells are left blank; see [Scroll::builder()]
+/// for wrapping the content around instead, and for the fill color. Under a
+/// reduced-motion preference, the content cuts to its scrolled position instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use tachyonfx::{fx, Shader};
+/// use tachyonfx::fx::Direction;
+///
+/// let log = Buffer::with_lines(vec!["1", "2", "3", "4"]);
+/// let area = log.area;
+/// let mut effect = fx::scroll(Direction::DownToUp, 2, 1000);
+///
+/// let mut buf = log.clone();
+/// effect.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(vec!["2", "3", "4", " "]));
+///
+/// let mut buf = log.clone();
+/// effect.process(Duration::from_millis(500), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(vec!["3", "4", " ", " "]));
+/// ```
+pub fn scroll<T: Into<EffectTimer>>(direction: Direction, distance: u16, lifetime: T) -> Effect {
+    Scroll::builder()
+        .direction(direction)
+        .distance(distance)
+        .lifetime(lifetime.into())
+        .into()
+}
+
+/// Runs the effect in an area of the given size, attached to an [Anchor] of the
+/// area the effect is processed in. The area is recomputed on every frame, so
+/// the effect follows its anchor when the layout changes.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Shader};
+/// use tachyonfx::fx::Anchor;
+///
+/// let mut toast = fx::at(fx::fade_to_fg(Color::Red, 10), Anchor::BottomRight, (2, 1));
+///
+/// let area = Rect::new(0, 0, 4, 2);
+/// let mut buf = Buffer::empty(area);
+/// toast.process(Duration::from_millis(10), &mut buf, area);
+///
+/// assert_eq!(buf.get(1, 1).fg, Color::Reset);
+/// assert_eq!(buf.get(2, 1).fg, Color::Red);
+/// assert_eq!(buf.get(3, 1).fg, Color::Red);
+/// ```
+pub fn at<S: Into<Size>>(effect: Effect, anchor: Anchor, size: S) -> Effect {
+    at_with_margin(effect, anchor, size, Margin::default())
+}
+
+/// Like [at()], but keeps the anchored area `margin` cells away from the edges
+/// of the area the effect is processed in.
+pub fn at_with_margin<S: Into<Size>>(
+    effect: Effect,
+    anchor: Anchor,
+    size: S,
+    margin: Margin,
+) -> Effect {
+    Anchored::new(effect, anchor, size.into(), margin).into_effect()
+}
+
 /// Runs the effects in sequence, one after the other. Reports completion
 /// once the last effect has completed.
+///
+/// Any time left over from an effect completing mid-frame is passed on to the
+/// next effect, so the next effect starts in the same frame.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// let mut effect = fx::sequence(vec![
+///     fx::sleep(100),
+///     fx::fade_to_fg(Color::Red, 100),
+/// ]);
+///
+/// // both effects complete exactly on a frame boundary
+/// assert_eq!(effect.process(Duration::from_millis(100), &mut buf, area), None);
+/// assert_eq!(effect.process(Duration::from_millis(100), &mut buf, area), Some(Duration::ZERO));
+/// assert_eq!(buf.get(0, 0).fg, Color::Red);
+/// assert!(effect.done());
+/// ```
 pub fn sequence(effects: Vec<Effect>) -> Effect {
     SequentialEffect::new(effects).into_effect()
 }
 
+/// Runs the effects in sequence, like [sequence()], but starts each effect
+/// `overlap` before the previous one completes, so that stages crossfade instead
+/// of cutting hard from one to the next.
+///
+/// The next effect starts once the remaining time of the previous effect's timer
+/// drops to `overlap`; it is processed for the part of the frame after that point.
+/// Effects without a timer of their own, e.g. nested sequences, are not overlapped:
+/// the next effect starts with their overflow once they complete. During the
+/// overlap, all running effects process the buffer in order, with later effects
+/// drawn over earlier ones. Completion is reported once every effect has completed,
+/// with the smallest overflow of the effects completing in the last frame.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// // the second fade starts after 60ms, 40ms before the first one ends
+/// let mut intro = fx::sequence_overlap(vec![
+///     fx::fade_to_fg(Color::Red, 100),
+///     fx::fade_to_fg(Color::Blue, 100),
+/// ], Duration::from_millis(40));
+///
+/// // the second fade has not started yet
+/// assert_eq!(intro.process(Duration::from_millis(60), &mut buf, area), None);
+/// assert!(intro.describe().children[1].timer.is_some_and(|t| !t.started()));
+///
+/// // 100ms into the second fade: the whole sequence takes 160ms
+/// assert_eq!(intro.process(Duration::from_millis(90), &mut buf, area), None);
+/// assert_eq!(intro.process(Duration::from_millis(20), &mut buf, area), Some(Duration::from_millis(10)));
+/// assert_eq!(buf.get(0, 0).fg, Color::Blue);
+/// assert!(intro.done());
+/// ```
+pub fn sequence_overlap(effects: Vec<Effect>, overlap: Duration) -> Effect {
+    OverlappingSequence::new(effects, overlap).into_effect()
+}
+
 /// Runs the effects in parallel, all at the same time. Reports completion
 /// once all effects have completed.
+///
+/// The effects are applied in the order given: each effect processes the output
+/// of the effects before it, so when several effects write to the same cell, the
+/// last one wins. Use [parallel_blend()] to blend overlapping writes instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// let mut effect = fx::parallel(vec![
+///     fx::fade_to_fg(Color::Red, 10),
+///     fx::fade_to_fg(Color::Blue, 10),
+/// ]);
+/// effect.process(Duration::from_millis(16), &mut buf, area);
+///
+/// assert_eq!(buf.get(0, 0).fg, Color::Blue);
+/// ```
 pub fn parallel(effects: Vec<Effect>) -> Effect {
     ParallelEffect::new(effects).into_effect()
 }
 
+/// Runs the effects in parallel, like [parallel()], but every effect processes
+/// the same input and cells written by more than one effect are blended according
+/// to `blend_mode`, pairwise in the order the effects are given. Cells written
+/// by a single effect are applied as-is.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// let mut effect = fx::parallel_blend(BlendMode::Average, vec![
+///     fx::fade_to_fg(Color::Rgb(200, 0, 0), 10),
+///     fx::fade_to_fg(Color::Rgb(0, 0, 200), 10),
+/// ]);
+/// effect.process(Duration::from_millis(16), &mut buf, area);
+///
+/// assert_eq!(buf.get(0, 0).fg, Color::Rgb(100, 0, 100));
+/// ```
+pub fn parallel_blend(blend_mode: BlendMode, effects: Vec<Effect>) -> Effect {
+    ParallelEffect::blended(effects, blend_mode).into_effect()
+}
+
+/// Runs a copy of the effect in each of the areas, all at the same time.
+/// Reports completion once all copies have completed.
+pub fn multi_area(effect: Effect, areas: Vec<Rect>) -> Effect {
+    multi_area_staggered(effect, areas, Duration::ZERO)
+}
+
+/// Runs a copy of the effect in each of the areas, like [multi_area()], but
+/// starts each copy `stagger` after the previous one, for a cascading reveal.
+/// Copies waiting for their turn render their initial frame.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let cards: Vec<Rect> = (0..3).map(|x| Rect::new(x * 2, 0, 2, 1)).collect();
+/// let screen = Rect::new(0, 0, 6, 1);
+/// let mut buf = Buffer::empty(screen);
+///
+/// let fade_in = fx::fade_from_fg(Color::Black, 100);
+/// let mut intro = fx::multi_area_staggered(fade_in, cards, Duration::from_millis(50));
+///
+/// // the first card is done, the last one has yet to start
+/// assert_eq!(intro.process(Duration::from_millis(100), &mut buf, screen), None);
+/// assert_eq!(buf.get(0, 0).fg, Color::Reset);
+/// assert_eq!(buf.get(4, 0).fg, Color::Black);
+///
+/// let overflow = intro.process(Duration::from_millis(120), &mut buf, screen);
+/// assert_eq!(overflow, Some(Duration::from_millis(20)));
+/// ```
+pub fn multi_area_staggered(effect: Effect, areas: Vec<Rect>, stagger: Duration) -> Effect {
+    MultiArea::new(effect, areas, stagger).into_effect()
+}
+
+/// Applies the effect at partial strength: the effect is rendered into a scratch
+/// buffer, and the cells it changes are blended over the original content at
+/// the given `opacity`. At `0.0` the content is untouched, and at `1.0` the
+/// effect applies fully. Colors are interpolated, while symbols and modifiers
+/// switch over at an opacity of `0.5`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Shader};
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.get_mut(0, 0).set_fg(Color::Rgb(0, 0, 0));
+///
+/// let mut subtle = fx::opacity(fx::fade_to_fg(Color::Rgb(255, 255, 255), 10), 0.5);
+/// subtle.process(Duration::from_millis(10), &mut buf, area);
+///
+/// assert_eq!(buf.get(0, 0).fg, Color::Rgb(128, 128, 128));
+/// ```
+pub fn opacity(effect: Effect, opacity: f32) -> Effect {
+    Opacity::new(effect, opacity).into_effect()
+}
+
+/// Like [opacity()], but with the opacity following the alpha of the timer,
+/// e.g. to gradually strengthen an effect.
+pub fn opacity_timed<T: Into<EffectTimer>>(effect: Effect, timer: T) -> Effect {
+    Opacity::timed(effect, timer.into()).into_effect()
+}
+
 /// Dissolves the current text into the new text over the specified duration. The
 /// `cycle_len` parameter specifies the number of cell states are tracked before
 /// it cycles and repeats.
+///
+/// See [Dissolve::builder()] for dissolving along a direction, or with a seeded rng.
 pub fn dissolve<T: Into<EffectTimer>>(cycle_len: usize, lifetime: T) -> Effect {
     Dissolve::new(lifetime.into(), cycle_len)
         .into_effect()
 }
 
+/// Dissolves the content in order of distance from the center of the area, so
+/// that it appears to explode outwards from the center or, if `inward` is set,
+/// to collapse into it. The front is corrected for the aspect ratio of cells,
+/// making it circular, and slightly jittered. See [Dissolve::builder()] for
+/// tuning the amount of jitter, or seeding it.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use tachyonfx::{fx, Shader};
+///
+/// let mut buf = Buffer::with_lines(vec![
+///     "abcdefghi",
+///     "jklmnopqr",
+///     "stuvwxyz0",
+/// ]);
+/// let area = buf.area;
+///
+/// let mut effect = fx::radial_dissolve(1000, false);
+/// effect.process(Duration::from_millis(300), &mut buf, area);
+///
+/// assert_eq!(buf.get(4, 1).symbol(), " "); // the center is gone
+/// assert_eq!(buf.get(0, 0).symbol(), "a"); // the corners remain
+/// ```
+pub fn radial_dissolve<T: Into<EffectTimer>>(lifetime: T, inward: bool) -> Effect {
+    Dissolve::builder()
+        .lifetime(lifetime.into())
+        .cycle_len(1000)
+        .radial(if inward { Radial::Inward } else { Radial::Outward })
+        .bias(0.9)
+        .into()
+}
+
+/// Assembles the text of the area from glyphs which fly in from random positions
+/// within, or just outside, the area, converging on their destinations over the
+/// lifetime of the effect. The background stays in place.
+///
+/// Use [Assemble::builder()] for a seedable rng and to configure how far outside
+/// the area glyphs start. Under a reduced-motion preference, the glyphs coalesce
+/// in place instead.
+pub fn assemble<T: Into<EffectTimer>>(lifetime: T) -> Effect {
+    Assemble::builder()
+        .lifetime(lifetime.into())
+        .into()
+}
+
 /// The reverse of [dissolve()].
 pub fn coalesce<T: Into<EffectTimer>>(cycle_len: usize, lifetime: T) -> Effect {
     let lifetime = lifetime.into().reversed();
@@ -211,6 +1211,36 @@ pub fn fade_from_fg<T: Into<EffectTimer>, C: Into<Color>>(
 }
 
 /// Fades to the specified the background and foreground colors over the specified duration.
+///
+/// Only the colors of the cells are written; modifiers such as `REVERSED` and `BOLD`
+/// are preserved. Note that terminals swap the colors of `REVERSED` cells when
+/// drawing them, so the `fg` color becomes the visible background of such cells.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Modifier};
+/// use tachyonfx::{fx, Shader};
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let selected = Modifier::REVERSED | Modifier::BOLD;
+///
+/// // the same buffer is processed every frame, without re-rendering it
+/// let mut buf = Buffer::empty(area);
+/// buf.get_mut(0, 0).modifier = selected;
+///
+/// let mut fade = fx::sequence(vec![
+///     fx::fade_to(Color::Black, Color::White, 500),
+///     fx::fade_from(Color::Red, Color::Blue, 500),
+///     fx::hsl_shift(Some([90.0, 0.0, 0.0]), None, 500),
+/// ]);
+/// while fade.running() {
+///     fade.process(Duration::from_millis(16), &mut buf, area);
+///     assert_eq!(buf.get(0, 0).modifier, selected);
+/// }
+/// ```
 pub fn fade_to<T: Into<EffectTimer>, C: Into<Color>>(
     fg: C,
     bg: C,
@@ -228,6 +1258,110 @@ pub fn fade_from<T: Into<EffectTimer>, C: Into<Color>>(
     fade(Some(fg), Some(bg), lifetime.into(), true)
 }
 
+/// Fades the foreground and background colors to `to`, like [fade_to()], but keeps
+/// the text readable: the fade of a cell is held back whenever fading it further
+/// would drop the WCAG contrast ratio between its foreground and background below
+/// `min_contrast`. The colors snap to `to` when the effect completes.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.get_mut(0, 0).set_fg(Color::Rgb(255, 255, 255)).set_bg(Color::Rgb(0, 0, 0));
+///
+/// let gray = Color::Rgb(128, 128, 128);
+/// let mut fade = fx::fade_readable(gray, 1000, 4.5);
+///
+/// for _ in 0..9 {
+///     fade.process(Duration::from_millis(100), &mut buf, area);
+///     let cell = buf.get(0, 0);
+///     assert!(contrast_ratio(cell.fg, cell.bg) >= 4.5);
+/// }
+///
+/// fade.process(Duration::from_millis(100), &mut buf, area);
+/// assert_eq!((buf.get(0, 0).fg, buf.get(0, 0).bg), (gray, gray));
+/// ```
+pub fn fade_readable<T: Into<EffectTimer>, C: Into<Color>>(
+    to: C,
+    lifetime: T,
+    min_contrast: f32,
+) -> Effect {
+    let to = to.into();
+    FadeColors::builder()
+        .fg(Some(to))
+        .bg(Some(to))
+        .lifetime(lifetime.into())
+        .min_contrast(min_contrast)
+        .into()
+}
+
+/// Renders a fading trail behind the cursor. The `cursor_position` function is
+/// polled each frame; cells newly visited by the cursor are highlighted in the
+/// trail color and decay back to their original colors over `decay`. While
+/// the cursor stays put, its cell decays like the rest of the trail. The
+/// intensity of the trail ramps up from none to full over `timer`, e.g. to fade
+/// the trail in; pass `0` for a trail at full intensity from the start. The
+/// effect runs indefinitely.
+///
+/// Use [CursorTrail::builder()] to configure the trail color, glyph and length;
+/// the trail color defaults to white.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Position, Rect};
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 3, 1);
+/// let white = Color::White;
+///
+/// let cursor = Rc::new(Cell::new(Position::new(0, 0)));
+/// let cursor_pos = cursor.clone();
+/// let mut trail = fx::cursor_trail(move || Some(cursor_pos.get()), 0, 400);
+///
+/// let mut frame = |ms: u64| {
+///     let mut buf = Buffer::empty(area);
+///     trail.process(Duration::from_millis(ms), &mut buf, area);
+///     [0, 1, 2].map(|x| buf.get(x, 0).bg)
+/// };
+///
+/// assert_eq!(frame(0), [white, Color::Reset, Color::Reset]);
+///
+/// cursor.set(Position::new(1, 0));
+/// let [decaying, head, _] = frame(100);
+/// assert_eq!(head, white);
+/// assert!(decaying != white && decaying != Color::Reset);
+///
+/// // an idle cursor doesn't light up again once its trail has decayed
+/// assert_eq!(frame(400), [Color::Reset; 3]);
+/// assert_eq!(frame(400), [Color::Reset; 3]);
+/// ```
+pub fn cursor_trail<F, T, D>(
+    cursor_position: F,
+    timer: T,
+    decay: D,
+) -> Effect
+    where F: FnMut() -> Option<Position> + 'static,
+          T: Into<EffectTimer>,
+          D: Into<EffectTimer>,
+{
+    CursorTrail::builder()
+        .position_fn(cursor_position)
+        .timer(timer.into())
+        .decay(decay.into())
+        .into()
+}
 
 /// Pauses for the specified duration.
 pub fn sleep<T: Into<EffectTimer>>(duration: T) -> Effect {
@@ -239,6 +1373,35 @@ pub fn consume_tick() -> Effect {
     ConsumeTick::default().into_effect()
 }
 
+/// Renders the final frame of the wrapped effect for as long as the effect would
+/// have run, in place of animating it. This is the instant-cut alternative of
+/// effects moving content around, see [Shader::reduced](crate::Shader::reduced),
+/// and the wrapped effect is never replaced by its own alternative.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Shader};
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+/// let mut effect = fx::cut(fx::fade_to_fg(Color::Red, 1000));
+///
+/// effect.process(Duration::from_millis(100), &mut buf, area);
+/// assert_eq!(buf.get(0, 0).fg, Color::Red);
+/// assert!(effect.running());
+///
+/// effect.process(Duration::from_millis(900), &mut buf, area);
+/// assert!(effect.done());
+/// ```
+pub fn cut(effect: Effect) -> Effect {
+    Cut::new(effect).into_effect()
+}
+
 /// An effect that forces the wrapped effect to never report completion,
 /// effectively making it run indefinitely. Once the effect reaches the end,
 /// it will continue to process the effect without advancing the duration.
@@ -252,6 +1415,146 @@ pub fn with_duration(duration: Duration, effect: Effect) -> Effect {
     effect.with_duration(duration)
 }
 
+/// Holds, without rendering anything or consuming any of the timeline, until
+/// `flag` is set, e.g. by a background task once data has loaded; from then on,
+/// it behaves exactly as the `then` effect. Inside a [sequence()], this gates
+/// the following effects on the flag.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Shader};
+///
+/// let loaded = Arc::new(AtomicBool::new(false));
+/// let mut reveal = fx::wait_for(loaded.clone(), fx::fade_from_fg(Color::Black, 100));
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// assert_eq!(reveal.process(Duration::from_millis(500), &mut buf, area), None);
+/// assert!(reveal.running());
+///
+/// loaded.store(true, Ordering::Release);
+/// reveal.process(Duration::from_millis(100), &mut buf, area);
+/// assert!(reveal.done());
+/// ```
+pub fn wait_for(flag: Arc<AtomicBool>, then: Effect) -> Effect {
+    WaitFor::new(flag, then).into_effect()
+}
+
+/// Wraps an effect and, once it completes, writes `final_buf` into the effect's
+/// area, eliminating any rounding drift of the last frame. Cells are copied to
+/// the same positions they occupy in `final_buf`; the animation itself is left
+/// untouched until the wrapped effect reports completion.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let area = Rect::new(0, 0, 4, 1);
+/// let layout = Buffer::with_lines(vec!["done"]);
+///
+/// let mut buf = Buffer::with_lines(vec!["...."]);
+/// let mut effect = fx::snap_to(fx::fade_to_fg(Color::Red, 100), layout.clone());
+///
+/// effect.process(Duration::from_millis(50), &mut buf, area);
+/// assert_eq!(buf.get(0, 0).symbol(), ".");
+///
+/// effect.process(Duration::from_millis(50), &mut buf, area);
+/// assert_eq!(buf, layout);
+/// ```
+pub fn snap_to(effect: Effect, final_buf: Buffer) -> Effect {
+    SnapTo::new(effect, final_buf).into_effect()
+}
+
+/// Captures the current content of the effect's area into `slot` of the store,
+/// then completes immediately, without consuming any time. Pair with [restore()]
+/// to return to the captured content later.
+pub fn capture(store: &SnapshotStore, slot: &str) -> Effect {
+    Capture::new(store.clone(), slot).into_effect()
+}
+
+/// Crossfades from the current content to the snapshot captured into `slot` by
+/// [capture()]. The snapshot is freed from the store once the restore starts.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use tachyonfx::{fx, Shader};
+/// use tachyonfx::fx::SnapshotStore;
+///
+/// let store = SnapshotStore::new();
+/// let mut buf = Buffer::with_lines(vec!["calm"]);
+/// let area = buf.area;
+///
+/// fx::capture(&store, "baseline").process(Duration::ZERO, &mut buf, area);
+/// assert!(store.contains("baseline"));
+///
+/// // ...the content changes, e.g. by a temporary glitch
+/// let mut buf = Buffer::with_lines(vec!["c@l#"]);
+///
+/// let mut restore = fx::restore(&store, "baseline", 100);
+/// restore.process(Duration::from_millis(100), &mut buf, area);
+///
+/// assert_eq!(buf, Buffer::with_lines(vec!["calm"]));
+/// assert!(!store.contains("baseline"));
+/// ```
+pub fn restore<T: Into<EffectTimer>>(
+    store: &SnapshotStore,
+    slot: &str,
+    lifetime: T,
+) -> Effect {
+    Restore::new(store.clone(), slot, lifetime.into()).into_effect()
+}
+
+/// Delays the start of the wrapped effect for each cell, proportionally to the
+/// cell's position along `axis`: cells at the start of the axis begin immediately,
+/// while cells at the far edge begin after `span`. Until a cell's delay has passed,
+/// it shows the first frame of the effect; completed cells hold the last frame
+/// until the entire effect is done.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+/// use tachyonfx::fx::GradientAxis;
+///
+/// let area = Rect::new(0, 0, 3, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// // fades in left-to-right; the rightmost column starts 200ms after the leftmost
+/// let fade = fx::fade_to_fg(Color::Red, 100);
+/// let mut effect = fx::gradient_delay(fade, GradientAxis::LeftToRight, Duration::from_millis(200));
+///
+/// effect.process(Duration::from_millis(100), &mut buf, area);
+/// assert_eq!(buf.get(0, 0).fg, Color::Red);
+/// assert_ne!(buf.get(2, 0).fg, Color::Red);
+///
+/// effect.process(Duration::from_millis(200), &mut buf, area);
+/// assert_eq!(buf.get(2, 0).fg, Color::Red);
+/// assert!(effect.done());
+/// ```
+pub fn gradient_delay(effect: Effect, axis: GradientAxis, span: Duration) -> Effect {
+    GradientDelay::new(effect, axis, span).into_effect()
+}
+
 /// Creates an effect that runs indefinitely but has an enforced duration,
 /// after which the effect will be marked as complete.
 pub fn timed_never_complete(duration: Duration, effect: Effect) -> Effect {
@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{CellIterator, EffectTimer, EffectTree};
+use crate::effect::{CellFilter, Effect};
+use crate::shader::Shader;
+
+/// Runs copies of an effect in several areas, starting each copy `stagger`
+/// after the previous one. Copies waiting for their turn are processed with
+/// a zero duration, rendering their initial frame.
+#[derive(Clone)]
+pub struct MultiArea {
+    effects: Vec<StaggeredEffect>,
+}
+
+#[derive(Clone)]
+struct StaggeredEffect {
+    fx: Effect,
+    remaining_delay: Duration,
+}
+
+impl MultiArea {
+    pub fn new(fx: Effect, areas: Vec<Rect>, stagger: Duration) -> Self {
+        let effects = areas.into_iter()
+            .enumerate()
+            .map(|(idx, area)| StaggeredEffect {
+                fx: fx.with_area(area),
+                remaining_delay: stagger * idx as u32,
+            })
+            .collect();
+
+        Self { effects }
+    }
+}
+
+impl StaggeredEffect {
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let duration = if self.remaining_delay >= duration {
+            self.remaining_delay -= duration;
+            Duration::ZERO
+        } else {
+            let d = duration - self.remaining_delay;
+            self.remaining_delay = Duration::ZERO;
+            d
+        };
+
+        self.fx.process(duration, buf, area)
+            .filter(|_| self.remaining_delay.is_zero())
+    }
+}
+
+impl Shader for MultiArea {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let mut remaining = Some(duration);
+
+        for effect in self.effects.iter_mut().filter(|e| e.fx.running()) {
+            match effect.process(duration, buf, area) {
+                None => remaining = None,
+                Some(d) if remaining.is_some() => {
+                    remaining = Some(d.min(remaining.unwrap()));
+                }
+                _ => (),
+            }
+        }
+
+        remaining
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.effects.iter().all(|e| e.fx.done())
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        None
+    }
+
+    fn set_area(&mut self, _area: Rect) {
+        // each copy keeps its own area
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.effects.iter_mut().for_each(|e| e.fx.set_cell_selection(strategy.clone()));
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.effects.iter_mut().for_each(|e| e.fx.set_cell_aspect(ratio));
+    }
+
+    fn reverse(&mut self) {
+        self.effects.iter_mut().for_each(|e| e.fx.reverse());
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        None
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(self.effects.iter().map(|e| e.fx.describe()).collect())
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.effects.iter().filter(|e| e.fx.running()).all(|e| {
+            e.remaining_delay > delta
+                || (e.remaining_delay.is_zero() && e.fx.is_noop_this_frame(delta))
+        })
+    }
+
+    fn skip_current(&mut self) {
+        self.effects.iter_mut()
+            .filter(|e| e.fx.running())
+            .for_each(|e| e.fx.skip_current());
+    }
+
+    fn skip_all(&mut self) {
+        self.effects.iter_mut()
+            .filter(|e| e.fx.running())
+            .for_each(|e| {
+                e.remaining_delay = Duration::ZERO;
+                e.fx.skip_all();
+            });
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        // the stage of the first area, which leads the others
+        self.effects.first().and_then(|e| e.fx.stage_index())
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.effects.first().and_then(|e| e.fx.stage_count())
+    }
+}
@@ -3,6 +3,7 @@ use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use crate::{CellIterator, EffectTimer};
 use crate::effect::{Effect, CellFilter};
+use crate::EffectTree;
 use crate::shader::Shader;
 
 #[derive(Clone)]
@@ -35,6 +36,10 @@ impl Shader for NeverComplete {
         self.effect.set_cell_selection(strategy);
     }
 
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.effect.set_cell_aspect(ratio);
+    }
+
     fn reverse(&mut self) {
         self.effect.reverse()
     }
@@ -46,4 +51,31 @@ impl Shader for NeverComplete {
     fn cell_selection(&self) -> Option<CellFilter> {
         self.effect.cell_selection()
     }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .infinite()
+            .with_children(vec![self.effect.describe()])
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        // a completed effect keeps rendering its final frame
+        self.effect.done() || self.effect.is_noop_this_frame(delta)
+    }
+
+    fn skip_all(&mut self) {
+        self.effect.skip_all();
+    }
+
+    fn skip_current(&mut self) {
+        self.effect.skip_current();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.effect.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.effect.stage_count()
+    }
 }
\ No newline at end of file
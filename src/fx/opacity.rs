@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{BlendMode, CellIterator, EffectTimer, EffectTree};
+use crate::effect::{CellFilter, Effect};
+use crate::shader::Shader;
+
+/// Applies the wrapped effect at partial strength, by blending the cells it
+/// changes back over the original content.
+#[derive(Clone)]
+pub struct Opacity {
+    fx: Effect,
+    opacity: OpacityLevel,
+}
+
+#[derive(Clone)]
+enum OpacityLevel {
+    Fixed(f32),
+    Timed(EffectTimer),
+}
+
+impl Opacity {
+    /// Applies the effect at a fixed opacity, from `0.0` to `1.0`.
+    pub fn new(fx: Effect, opacity: f32) -> Self {
+        Self { fx, opacity: OpacityLevel::Fixed(opacity.clamp(0.0, 1.0)) }
+    }
+
+    /// Applies the effect at an opacity following the alpha of the timer.
+    pub fn timed(fx: Effect, timer: EffectTimer) -> Self {
+        Self { fx, opacity: OpacityLevel::Timed(timer) }
+    }
+
+    fn alpha(&self) -> f32 {
+        match &self.opacity {
+            OpacityLevel::Fixed(opacity) => *opacity,
+            OpacityLevel::Timed(timer)   => timer.alpha(),
+        }
+    }
+}
+
+impl Shader for Opacity {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        if let OpacityLevel::Timed(timer) = &mut self.opacity {
+            timer.process(duration);
+        }
+
+        let mut layer = buf.clone();
+        let effect_area = self.fx.area().unwrap_or(area);
+        let overflow = self.fx.process(duration, &mut layer, effect_area);
+
+        let alpha = self.alpha();
+        buf.content.iter_mut()
+            .zip(layer.content.iter())
+            .filter(|(original, layer)| original != layer)
+            .for_each(|(cell, layer)| *cell = BlendMode::Replace.blend_cell(cell, layer, alpha));
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.fx.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.fx.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.fx.set_area(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.fx.set_cell_selection(strategy);
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.fx.set_cell_aspect(ratio);
+    }
+
+    fn reverse(&mut self) {
+        self.fx.reverse();
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(vec![self.fx.describe()])
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        let fading = matches!(self.opacity, OpacityLevel::Timed(timer) if !timer.done());
+        !fading && self.fx.is_noop_this_frame(delta)
+    }
+
+    fn skip_current(&mut self) {
+        self.fx.skip_current();
+    }
+
+    fn skip_all(&mut self) {
+        if let OpacityLevel::Timed(timer) = &mut self.opacity {
+            timer.finish();
+        }
+        self.fx.skip_all();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.fx.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.stage_count()
+    }
+}
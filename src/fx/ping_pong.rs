@@ -3,7 +3,7 @@ use ratatui::layout::Rect;
 
 use std::time::Duration;
 
-use crate::{CellFilter, CellIterator, Effect, EffectTimer, Shader};
+use crate::{CellFilter, CellIterator, Effect, EffectTimer, EffectTree, Shader};
 
 #[derive(Clone)]
 pub struct PingPong {
@@ -69,6 +69,11 @@ impl Shader for PingPong {
         self.strategy = strategy;
     }
 
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.fx.set_cell_aspect(ratio);
+        self.fx_original.set_cell_aspect(ratio);
+    }
+
     fn reverse(&mut self) {
         self.fx.reverse();
     }
@@ -77,7 +82,43 @@ impl Shader for PingPong {
         self.fx.timer_mut()
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        self.fx.timer()
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.strategy.clone())
     }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(vec![self.fx.describe()])
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.fx.is_noop_this_frame(delta)
+    }
+
+    fn skip_current(&mut self) {
+        self.fx.skip_current();
+    }
+
+    fn skip_all(&mut self) {
+        // both passes are skipped, ending where the effect started
+        if !self.is_reversing {
+            self.is_reversing = true;
+            self.fx = self.fx_original.clone();
+            self.fx.reverse();
+        }
+        self.fx.skip_all();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.fx.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.stage_count()
+    }
 }
\ No newline at end of file
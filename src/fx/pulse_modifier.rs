@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Modifier;
+
+use crate::CellIterator;
+use crate::effect::CellFilter;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// Adds a style modifier during the first half of the effect's lifetime
+/// and removes it during the second half. Cells which already carried the
+/// modifier keep it throughout.
+#[derive(Clone)]
+pub struct PulseModifier {
+    modifier: Modifier,
+    lifetime: EffectTimer,
+    /// Cells the modifier was added to, as opposed to those carrying it already.
+    pulsed: HashSet<Position>,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl PulseModifier {
+    pub fn new(modifier: Modifier, lifetime: EffectTimer) -> Self {
+        Self {
+            modifier,
+            lifetime,
+            pulsed: HashSet::new(),
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+}
+
+impl Shader for PulseModifier {
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let is_on = alpha < 0.5;
+
+        for (pos, cell) in cell_iter {
+            if is_on {
+                if !cell.modifier.contains(self.modifier) {
+                    cell.modifier.insert(self.modifier);
+                    self.pulsed.insert(pos);
+                }
+            } else if self.pulsed.contains(&pos) {
+                cell.modifier.remove(self.modifier);
+            }
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area)
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
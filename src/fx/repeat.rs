@@ -1,3 +1,4 @@
+use std::rc::Rc;
 use std::time::Duration;
 
 use ratatui::buffer::Buffer;
@@ -5,6 +6,7 @@ use ratatui::prelude::Rect;
 use crate::{CellIterator, EffectTimer};
 
 use crate::effect::{Effect, CellFilter};
+use crate::EffectTree;
 use crate::shader::Shader;
 
 #[derive(Clone)]
@@ -81,11 +83,7 @@ impl Shader for Repeat {
     }
 
     fn done(&self) -> bool {
-        match self.mode {
-            RepeatMode::Times(0)                 => true,
-            RepeatMode::Duration(Duration::ZERO) => true,
-            _                                    => false,
-        }
+        matches!(self.mode, RepeatMode::Times(0) | RepeatMode::Duration(Duration::ZERO))
     }
 
     fn clone_box(&self) -> Box<dyn Shader> {
@@ -97,11 +95,18 @@ impl Shader for Repeat {
     }
 
     fn set_area(&mut self, area: Rect) {
-        self.fx.set_area(area)
+        self.fx.set_area(area);
+        self.original.set_area(area);
     }
 
     fn set_cell_selection(&mut self, strategy: CellFilter) {
-        self.fx.set_cell_selection(strategy);
+        self.fx.set_cell_selection(strategy.clone());
+        self.original.set_cell_selection(strategy);
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.fx.set_cell_aspect(ratio);
+        self.original.set_cell_aspect(ratio);
     }
 
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
@@ -111,6 +116,44 @@ impl Shader for Repeat {
     fn cell_selection(&self) -> Option<CellFilter> {
         self.fx.cell_selection()
     }
+
+    fn describe(&self) -> EffectTree {
+        let tree = EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(vec![self.fx.describe()]);
+
+        match self.mode {
+            RepeatMode::Forever => tree.infinite(),
+            _                   => tree,
+        }
+    }
+
+    fn skip_all(&mut self) {
+        // the current iteration becomes the last one
+        if !self.done() {
+            self.fx.skip_all();
+            self.mode = RepeatMode::Times(1);
+        }
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        match self.mode {
+            RepeatMode::Duration(d) => d > delta && self.fx.is_noop_this_frame(delta),
+            _                       => self.fx.is_noop_this_frame(delta),
+        }
+    }
+
+    fn skip_current(&mut self) {
+        self.fx.skip_current();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.fx.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.stage_count()
+    }
 }
 
 #[derive(Clone)]
@@ -118,4 +161,124 @@ pub enum RepeatMode {
     Forever,
     Times(u32),
     Duration(Duration),
-}
\ No newline at end of file
+}
+type TimeCurveFn = dyn Fn(u32) -> f32;
+
+/// Repeats an effect a number of times, scaling the duration of each iteration
+/// by the factor the time curve returns for the iteration's index.
+#[derive(Clone)]
+pub struct RepeatRamp {
+    fx: Effect,
+    original: Effect,
+    count: u32,
+    iteration: u32,
+    time_curve: Rc<TimeCurveFn>,
+}
+
+impl RepeatRamp {
+    pub fn new<F>(fx: Effect, count: u32, time_curve: F) -> Self
+        where F: Fn(u32) -> f32 + 'static
+    {
+        let original = fx.clone();
+        Self { fx, original, count, iteration: 0, time_curve: Rc::new(time_curve) }
+    }
+}
+
+impl Shader for RepeatRamp {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let mut remaining = duration;
+
+        while !self.done() {
+            // time runs slower for the effect when its iteration is stretched
+            let scale = (self.time_curve)(self.iteration).max(f32::EPSILON) as f64;
+            let overflow = self.fx.process(remaining.div_f64(scale), buf, area);
+
+            if !self.fx.done() {
+                return None;
+            }
+
+            self.iteration += 1;
+            if self.iteration < self.count {
+                self.fx = self.original.clone();
+            }
+
+            match overflow {
+                Some(overflow) => remaining = overflow.mul_f64(scale),
+                None           => return None,
+            }
+        }
+
+        Some(remaining)
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // nothing to do
+    }
+
+    fn done(&self) -> bool {
+        self.iteration >= self.count
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.fx.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.fx.set_area(area);
+        self.original.set_area(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.fx.set_cell_selection(strategy.clone());
+        self.original.set_cell_selection(strategy);
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.fx.set_cell_aspect(ratio);
+        self.original.set_cell_aspect(ratio);
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_done(self.done())
+            .with_children(vec![self.fx.describe()])
+    }
+
+    fn skip_all(&mut self) {
+        // the current iteration becomes the last one
+        if !self.done() {
+            self.fx.skip_all();
+            self.iteration = self.count - 1;
+        }
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        let scale = (self.time_curve)(self.iteration).max(f32::EPSILON) as f64;
+        !self.done() && self.fx.is_noop_this_frame(delta.div_f64(scale))
+    }
+
+    fn skip_current(&mut self) {
+        self.fx.skip_current();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.fx.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.stage_count()
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::{Line, Span};
+
+use crate::CellIterator;
+use crate::effect::CellFilter;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// Types out a title in the top border of the area, one character at a time.
+/// The title is placed according to the alignment of the line, between the
+/// corners of the border, as a `Block` would place it.
+#[derive(Clone)]
+pub struct RevealTitle {
+    title: Line<'static>,
+    lifetime: EffectTimer,
+    area: Option<Rect>,
+}
+
+impl RevealTitle {
+    pub fn new(title: Line<'static>, lifetime: EffectTimer) -> Self {
+        Self { title, lifetime, area: None }
+    }
+
+    /// Returns the title, truncated to its first `n` characters.
+    fn truncated(&self, n: usize) -> Line<'static> {
+        let mut remaining = n;
+        let spans: Vec<Span<'static>> = self.title.spans.iter()
+            .map_while(|span| {
+                if remaining == 0 {
+                    return None;
+                }
+
+                let content: String = span.content.chars().take(remaining).collect();
+                remaining -= content.chars().count();
+                Some(Span::styled(content, span.style))
+            })
+            .collect();
+
+        Line::from(spans)
+    }
+}
+
+impl Shader for RevealTitle {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.lifetime.process(duration);
+        if area.width <= 2 || area.height == 0 {
+            return overflow;
+        }
+
+        let len = self.title.spans.iter()
+            .map(|s| s.content.chars().count())
+            .sum::<usize>();
+        let revealed = (len as f32 * self.lifetime.alpha()).round() as usize;
+
+        // positioned by the full title, so that the title doesn't move as it is typed out
+        let max_width = area.width - 2;
+        let width = (self.title.width() as u16).min(max_width);
+        let x = area.x + 1 + match self.title.alignment {
+            Some(Alignment::Center) => (max_width - width) / 2,
+            Some(Alignment::Right)  => max_width - width,
+            _                       => 0,
+        };
+
+        buf.set_line(x, area.y, &self.truncated(revealed), width);
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+}
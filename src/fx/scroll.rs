@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Offset, Rect};
+use ratatui::style::Color;
+
+use crate::CellIterator;
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::effect_timer::EffectTimer;
+use crate::fx::Direction;
+use crate::fx::slide::shift_cells;
+use crate::shader::Shader;
+
+/// Scrolls the existing content of the area by `distance` cells in the given
+/// direction. Cells vacated by the scroll are either filled with blank cells,
+/// colored `fill_bg`, or, with `wrap`, by the content scrolled out on the
+/// opposite side.
+#[derive(Builder, Clone)]
+#[builder(pattern = "owned")]
+pub struct Scroll {
+    /// The direction the content moves in, e.g. `DownToUp` scrolls the content up.
+    direction: Direction,
+    /// The distance scrolled by the end of the effect, in cells.
+    distance: u16,
+    lifetime: EffectTimer,
+    #[builder(default)]
+    wrap: bool,
+    #[builder(default = "Color::Reset")]
+    fill_bg: Color,
+    #[builder(default)]
+    area: Option<Rect>,
+}
+
+impl Scroll {
+    pub fn builder() -> ScrollBuilder {
+        ScrollBuilder::default()
+    }
+}
+
+impl From<ScrollBuilder> for Effect {
+    fn from(value: ScrollBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+/// Shifts the content of the area by `offset`, wrapping cells moved out of
+/// the area around to the opposite side.
+fn wrap_cells(buf: &mut Buffer, area: Rect, offset: Offset) {
+    let area = area.intersection(buf.area);
+    if area.is_empty() {
+        return;
+    }
+
+    let content = buf.clone();
+    let (w, h) = (area.width as i32, area.height as i32);
+    for y in 0..area.height {
+        for x in 0..area.width {
+            let src_x = (x as i32 - offset.x).rem_euclid(w) as u16;
+            let src_y = (y as i32 - offset.y).rem_euclid(h) as u16;
+            *buf.get_mut(area.x + x, area.y + y) =
+                content.get(area.x + src_x, area.y + src_y).clone();
+        }
+    }
+}
+
+impl Shader for Scroll {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.lifetime.process(duration);
+        let distance = (self.distance as f32 * self.lifetime.alpha()).round() as i32;
+
+        let offset = match self.direction {
+            Direction::LeftToRight => Offset { x: distance, y: 0 },
+            Direction::RightToLeft => Offset { x: -distance, y: 0 },
+            Direction::UpToDown    => Offset { x: 0, y: distance },
+            Direction::DownToUp    => Offset { x: 0, y: -distance },
+        };
+
+        if self.wrap {
+            wrap_cells(buf, area, offset);
+        } else {
+            let mut fill = Cell::default();
+            fill.set_bg(self.fill_bg);
+            shift_cells(buf, area, offset, &fill);
+        }
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn reduced(&self) -> Option<Effect> {
+        Some(crate::fx::cut(Effect::new(self.clone())))
+    }
+}
@@ -7,9 +7,11 @@ use ratatui::layout::Rect;
 
 use crate::{CellFilter, CellIterator, EffectTimer, Shader};
 
+type ShaderFnSignature = dyn FnMut(f32, Duration, Rect, CellIterator);
+
 #[derive(Clone)]
 pub struct ShaderFn {
-    code: Rc<RefCell<dyn FnMut(f32, Duration, Rect, CellIterator)>>,
+    code: Rc<RefCell<ShaderFnSignature>>,
     timer: EffectTimer,
     cell_filter: Option<CellFilter>,
     area: Option<Rect>,
@@ -74,4 +76,8 @@ impl Shader for ShaderFn {
     fn cell_selection(&self) -> Option<CellFilter> {
         self.cell_filter.clone()
     }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
 
+use std::time::Duration;
+
 use ratatui::layout::Rect;
 use crate::CellIterator;
 
@@ -39,9 +41,17 @@ impl Shader for Sleep {
         Some(&mut self.timer)
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         None
     }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.timer.remaining() > delta
+    }
 }
 
 
@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Offset, Rect};
+use ratatui::style::Color;
+
+use crate::{BufferRenderer, CellIterator, Interpolation};
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::effect_timer::EffectTimer;
+use crate::fx::Direction;
+use crate::shader::Shader;
+
+/// The share of the lifetime spent sliding in, up to the overshoot; the rest is
+/// spent settling back to the resting position.
+const SLIDE_SHARE: f32 = 0.7;
+
+/// Slides the content of the area in from one side, overshooting its resting
+/// position by `overshoot` cells before settling. Regions of the area exposed
+/// by the slide are filled with blank cells, colored `fill_bg`.
+#[derive(Builder, Clone)]
+#[builder(pattern = "owned")]
+pub struct SlideIn {
+    /// The direction the content moves in, e.g. `LeftToRight` enters from the left.
+    direction: Direction,
+    lifetime: EffectTimer,
+    /// How far past its resting position the content travels, in cells.
+    #[builder(default = "2")]
+    overshoot: u16,
+    #[builder(default = "Color::Reset")]
+    fill_bg: Color,
+    #[builder(default)]
+    area: Option<Rect>,
+}
+
+impl SlideIn {
+    pub fn builder() -> SlideInBuilder {
+        SlideInBuilder::default()
+    }
+
+    /// Returns the distance of the content from its resting position, in cells,
+    /// along the direction of movement; negative values are short of the resting
+    /// position and positive values past it.
+    fn displacement(&self, alpha: f32, area: Rect) -> f32 {
+        let distance = match self.direction {
+            Direction::LeftToRight | Direction::RightToLeft => area.width,
+            Direction::UpToDown | Direction::DownToUp       => area.height,
+        } as f32;
+        let overshoot = self.overshoot as f32;
+
+        if alpha < SLIDE_SHARE {
+            let a = Interpolation::QuadOut.alpha(alpha / SLIDE_SHARE);
+            -distance + (distance + overshoot) * a
+        } else {
+            let a = Interpolation::SineInOut.alpha((alpha - SLIDE_SHARE) / (1.0 - SLIDE_SHARE));
+            overshoot * (1.0 - a)
+        }
+    }
+}
+
+impl From<SlideInBuilder> for Effect {
+    fn from(value: SlideInBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+/// Shifts the content of the area by `offset`, filling exposed cells with `fill`.
+pub(crate) fn shift_cells(buf: &mut Buffer, area: Rect, offset: Offset, fill: &Cell) {
+    let area = area.intersection(buf.area);
+    let mut content = Buffer::empty(area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            *content.get_mut(x, y) = buf.get(x, y).clone();
+        }
+    }
+
+    content.render_buffer_or(Offset { x: -offset.x, y: -offset.y }, buf, area, fill);
+}
+
+impl Shader for SlideIn {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.lifetime.process(duration);
+        let displacement = self.displacement(self.lifetime.alpha(), area).round() as i32;
+
+        let offset = match self.direction {
+            Direction::LeftToRight => Offset { x: displacement, y: 0 },
+            Direction::RightToLeft => Offset { x: -displacement, y: 0 },
+            Direction::UpToDown    => Offset { x: 0, y: displacement },
+            Direction::DownToUp    => Offset { x: 0, y: -displacement },
+        };
+
+        let mut fill = Cell::default();
+        fill.set_bg(self.fill_bg);
+        shift_cells(buf, area, offset, &fill);
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn reduced(&self) -> Option<Effect> {
+        let fade = crate::fx::fade_from(self.fill_bg, self.fill_bg, self.lifetime);
+        Some(match self.area {
+            Some(area) => fade.with_area(area),
+            None       => fade,
+        })
+    }
+}
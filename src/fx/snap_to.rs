@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Offset, Rect};
+
+use crate::{BufferRenderer, CellIterator, EffectTimer};
+use crate::effect::{CellFilter, Effect};
+use crate::EffectTree;
+use crate::shader::Shader;
+
+/// Writes a known final buffer into the area once the wrapped effect completes.
+#[derive(Clone)]
+pub struct SnapTo {
+    fx: Effect,
+    final_buf: Buffer,
+}
+
+impl SnapTo {
+    pub fn new(fx: Effect, final_buf: Buffer) -> Self {
+        Self { fx, final_buf }
+    }
+}
+
+impl Shader for SnapTo {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.fx.process(duration, buf, area);
+
+        if self.fx.done() {
+            let offset = Offset {
+                x: area.x as i32 - self.final_buf.area.x as i32,
+                y: area.y as i32 - self.final_buf.area.y as i32,
+            };
+            self.final_buf.render_buffer(offset, buf, area);
+        }
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // nothing to do
+    }
+
+    fn done(&self) -> bool {
+        self.fx.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.fx.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.fx.set_area(area)
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.fx.set_cell_selection(strategy);
+    }
+
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.fx.set_cell_aspect(ratio);
+    }
+
+    fn reverse(&mut self) {
+        self.fx.reverse()
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        self.fx.timer_mut()
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        self.fx.timer()
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_timer(self.timer())
+            .with_done(self.done())
+            .with_children(vec![self.fx.describe()])
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.fx.is_noop_this_frame(delta)
+    }
+
+    fn skip_current(&mut self) {
+        self.fx.skip_current();
+    }
+
+    fn skip_all(&mut self) {
+        self.fx.skip_all();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.fx.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.stage_count()
+    }
+}
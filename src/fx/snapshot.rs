@@ -0,0 +1,191 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{BlendMode, CellIterator, EffectTimer};
+use crate::effect::CellFilter;
+use crate::shader::Shader;
+
+/// A shared store of named buffer snapshots, written by [capture](crate::fx::capture)
+/// and read by [restore](crate::fx::restore). Clones of the store share the
+/// same slots.
+///
+/// A captured slot is freed once a `restore` effect starts restoring it, or
+/// when it is explicitly cleared. Capturing into an occupied slot replaces the
+/// previous snapshot.
+#[derive(Clone, Default)]
+pub struct SnapshotStore {
+    slots: Rc<RefCell<HashMap<String, Buffer>>>,
+}
+
+impl SnapshotStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the slot holds a snapshot.
+    pub fn contains(&self, slot: &str) -> bool {
+        self.slots.borrow().contains_key(slot)
+    }
+
+    /// Frees the snapshot held by the slot, if any.
+    pub fn clear(&self, slot: &str) {
+        self.slots.borrow_mut().remove(slot);
+    }
+
+    /// Frees all snapshots.
+    pub fn clear_all(&self) {
+        self.slots.borrow_mut().clear();
+    }
+
+    fn insert(&self, slot: &str, snapshot: Buffer) {
+        self.slots.borrow_mut().insert(slot.to_string(), snapshot);
+    }
+
+    fn take(&self, slot: &str) -> Option<Buffer> {
+        self.slots.borrow_mut().remove(slot)
+    }
+}
+
+/// Captures the content of the area into a slot of the store, then completes
+/// without consuming any time.
+#[derive(Clone)]
+pub struct Capture {
+    store: SnapshotStore,
+    slot: String,
+    area: Option<Rect>,
+    captured: bool,
+}
+
+impl Capture {
+    pub fn new(store: SnapshotStore, slot: &str) -> Self {
+        Self { store, slot: slot.to_string(), area: None, captured: false }
+    }
+}
+
+impl Shader for Capture {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let area = area.intersection(buf.area);
+        let mut snapshot = Buffer::empty(area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                *snapshot.get_mut(x, y) = buf.get(x, y).clone();
+            }
+        }
+
+        self.store.insert(&self.slot, snapshot);
+        self.captured = true;
+
+        Some(duration)
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.captured
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+}
+
+/// Crossfades from the current content of the area to a snapshot previously
+/// captured into a slot of the store. The snapshot is taken out of the store
+/// on the first frame; if the slot is empty, the effect leaves the content
+/// untouched for its duration.
+#[derive(Clone)]
+pub struct Restore {
+    store: SnapshotStore,
+    slot: String,
+    snapshot: Option<Buffer>,
+    taken: bool,
+    lifetime: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Restore {
+    pub fn new(store: SnapshotStore, slot: &str, lifetime: EffectTimer) -> Self {
+        Self {
+            store,
+            slot: slot.to_string(),
+            snapshot: None,
+            taken: false,
+            lifetime,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+}
+
+impl Shader for Restore {
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        if !self.taken {
+            self.snapshot = self.store.take(&self.slot);
+            self.taken = true;
+        }
+
+        let Some(snapshot) = self.snapshot.as_ref() else { return };
+
+        cell_iter
+            .filter(|(pos, _)| snapshot.area.contains(*pos))
+            .for_each(|(pos, cell)| {
+                let captured = snapshot.get(pos.x, pos.y);
+                *cell = BlendMode::Replace.blend_cell(cell, captured, alpha);
+            });
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
@@ -0,0 +1,117 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::{CellIterator, ColorMapper, Interpolatable};
+use crate::effect::CellFilter;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+
+/// Transitions the style of cells from one [Style] to another.
+///
+/// The colors (foreground, background and underline) are interpolated; a color
+/// which is unset in one of the styles is taken from the cell itself. The
+/// modifiers are discrete: those of `from` are applied while the alpha is below
+/// `0.5`, and those of `to` from `0.5` onwards, replacing the modifiers added
+/// by `from`. Symbols are left untouched.
+#[derive(Clone)]
+pub struct StyleTransition {
+    from: Style,
+    to: Style,
+    lifetime: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl StyleTransition {
+    pub fn new(from: Style, to: Style, lifetime: EffectTimer) -> Self {
+        Self {
+            from,
+            to,
+            lifetime,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+}
+
+/// Interpolates between the two colors, falling back to the cell's color for
+/// an unset endpoint; returns `cell` if neither endpoint is set.
+fn transition(
+    mapper: &mut ColorMapper,
+    cell: Color,
+    from: Option<Color>,
+    to: Option<Color>,
+    alpha: f32,
+) -> Color {
+    if from.is_none() && to.is_none() {
+        return cell;
+    }
+
+    let from = from.unwrap_or(cell);
+    let to = to.unwrap_or(cell);
+    mapper.map(cell, alpha, |_| from.lerp(&to, alpha))
+}
+
+impl Shader for StyleTransition {
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        // past the switch, the modifiers of `from` are replaced by those of `to`;
+        // buffers which aren't re-rendered every frame would otherwise keep both
+        let (modifiers, replaced) = if alpha < 0.5 {
+            (self.from, Modifier::empty())
+        } else {
+            (self.to, self.from.add_modifier - self.to.add_modifier)
+        };
+
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+        let mut underline_mapper = ColorMapper::default();
+
+        cell_iter.for_each(|(_, cell)| {
+            cell.fg = transition(&mut fg_mapper, cell.fg, self.from.fg, self.to.fg, alpha);
+            cell.bg = transition(&mut bg_mapper, cell.bg, self.from.bg, self.to.bg, alpha);
+            cell.underline_color = transition(
+                &mut underline_mapper,
+                cell.underline_color,
+                self.from.underline_color,
+                self.to.underline_color,
+                alpha,
+            );
+
+            cell.modifier.remove(replaced);
+            cell.modifier.insert(modifiers.add_modifier);
+            cell.modifier.remove(modifiers.sub_modifier);
+        });
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area)
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
@@ -0,0 +1,153 @@
+use derive_builder::Builder;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::{CellIterator, ColorMapper, Interpolatable};
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::effect_timer::EffectTimer;
+use crate::fx::Direction;
+use crate::fx::feather::feather_alpha;
+use crate::shader::Shader;
+
+/// Reveals the content behind a glowing band of color, which sweeps across
+/// the area. Cells ahead of the band are hidden, cells within the band fade
+/// from the edge color at the leading edge into their own colors at the
+/// trailing edge, and cells behind the band are left untouched.
+///
+/// With a `feather`, the glyphs within `feather` cells ahead of the band are
+/// faded in from their background color to the edge color, softening the
+/// leading edge.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::style::Color;
+/// use tachyonfx::{Effect, Shader};
+/// use tachyonfx::fx::{Direction, SweepGradient};
+///
+/// let mut buf = Buffer::with_lines(vec!["abcdefgh"]);
+/// let area = buf.area;
+///
+/// let mut reveal: Effect = SweepGradient::builder()
+///     .direction(Direction::LeftToRight)
+///     .edge_color(Color::Rgb(255, 255, 255))
+///     .width(2)
+///     .feather(2.0)
+///     .lifetime(1000.into())
+///     .into();
+/// reveal.process(Duration::from_millis(500), &mut buf, area);
+///
+/// assert_eq!(buf.get(4, 0).symbol(), "e"); // within the glowing band
+/// assert_eq!(buf.get(6, 0).symbol(), "g"); // feathered, halfway faded in
+/// assert_eq!(buf.get(6, 0).fg, Color::Rgb(128, 128, 128));
+/// assert_eq!(buf.get(7, 0).symbol(), " "); // not yet revealed
+/// ```
+#[derive(Builder, Clone)]
+#[builder(pattern = "owned")]
+pub struct SweepGradient {
+    direction: Direction,
+    edge_color: Color,
+    /// The width of the glowing band, in cells.
+    width: u16,
+    lifetime: EffectTimer,
+    /// The width of the soft edge ahead of the band, in cells.
+    #[builder(default)]
+    feather: f32,
+    #[builder(default)]
+    area: Option<Rect>,
+    #[builder(default)]
+    cell_filter: CellFilter,
+}
+
+impl SweepGradient {
+    pub fn builder() -> SweepGradientBuilder {
+        SweepGradientBuilder::default()
+    }
+
+    /// Returns the distance of the cell from the start of the area, in cells,
+    /// along the sweep direction, and the length of the area along it.
+    fn axis_distance(&self, pos: Position, area: Rect) -> (f32, f32) {
+        let x = (pos.x - area.x) as f32;
+        let y = (pos.y - area.y) as f32;
+        let w = area.width as f32;
+        let h = area.height as f32;
+
+        match self.direction {
+            Direction::LeftToRight => (x, w),
+            Direction::RightToLeft => (w - 1.0 - x, w),
+            Direction::UpToDown    => (y, h),
+            Direction::DownToUp    => (h - 1.0 - y, h),
+        }
+    }
+}
+
+impl From<SweepGradientBuilder> for Effect {
+    fn from(value: SweepGradientBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+impl Shader for SweepGradient {
+    fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator) {
+        let width = self.width.max(1) as f32;
+
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+
+        cell_iter.for_each(|(pos, cell)| {
+            let (distance, len) = self.axis_distance(pos, area);
+            let leading_edge = alpha * (len + width);
+            let offset = leading_edge - distance;
+
+            if offset <= 0.0 {
+                match feather_alpha(offset + self.feather, self.feather) {
+                    0.0 => { cell.set_char(' '); },
+                    a => {
+                        let fg = cell.bg.lerp(&self.edge_color, a);
+                        cell.set_fg(fg);
+                    },
+                }
+            } else if offset < width {
+                let a = offset / width;
+                let fg = fg_mapper.map(cell.fg, a, |c| self.edge_color.lerp(&c, a));
+                let bg = bg_mapper.map(cell.bg, a, |c| self.edge_color.lerp(&c, a));
+
+                cell.set_fg(fg);
+                cell.set_bg(bg);
+            }
+        });
+    }
+
+    fn done(&self) -> bool {
+        self.lifetime.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area)
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.lifetime)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
@@ -8,6 +8,7 @@ use Interpolation::CircOut;
 use crate::{CellIterator, ColorMapper};
 use crate::effect::CellFilter;
 use crate::effect_timer::EffectTimer;
+use crate::fx::feather::feather_alpha;
 use crate::interpolation::{Interpolatable, Interpolation};
 use crate::shader::Shader;
 
@@ -132,6 +133,10 @@ impl Shader for SweepIn {
         Some(&mut self.lifetime)
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.cell_filter.clone())
     }
@@ -144,32 +149,16 @@ fn window_alpha_fn(
     let gradient_len = gradient.end - gradient.start;
     match direction {
         Direction::LeftToRight => Box::new(move |p: Position| -> f32 {
-            match p.x as f32 {
-                x if gradient.contains(&x) => 1.0 - (x - gradient.start) / gradient_len,
-                x if x < gradient.start    => 1.0,
-                _                          => 0.0,
-            }
+            feather_alpha(gradient.end - p.x as f32, gradient_len)
         }),
         Direction::RightToLeft => Box::new(move |p: Position| -> f32 {
-            match p.x as f32 {
-                x if gradient.contains(&x) => (x - gradient.start) / gradient_len,
-                x if x >= gradient.end     => 1.0,
-                _                          => 0.0,
-            }
+            feather_alpha(p.x as f32 - gradient.start, gradient_len)
         }),
         Direction::UpToDown => Box::new(move |p: Position| -> f32 {
-            match p.y as f32 {
-                y if gradient.contains(&y) => 1.0 - (y - gradient.start) / gradient_len,
-                y if y < gradient.start    => 1.0,
-                _                          => 0.0,
-            }
+            feather_alpha(gradient.end - p.y as f32, gradient_len)
         }),
         Direction::DownToUp => Box::new(move |p: Position| -> f32 {
-            match p.y as f32 {
-                y if gradient.contains(&y) => (y - gradient.start) / gradient_len,
-                y if y >= gradient.start   => 1.0,
-                _                          => 0.0,
-            }
+            feather_alpha(p.y as f32 - gradient.start, gradient_len)
         }),
     }
-}
\ No newline at end of file
+}
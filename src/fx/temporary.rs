@@ -5,6 +5,7 @@ use crate::CellIterator;
 use crate::effect::{Effect, CellFilter, IntoEffect};
 use crate::effect_timer::EffectTimer;
 use crate::interpolation::Interpolation::Linear;
+use crate::EffectTree;
 use crate::shader::Shader;
 
 #[derive(Clone)]
@@ -56,13 +57,49 @@ impl Shader for TemporaryEffect {
         self.effect.set_cell_selection(strategy);
     }
 
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        self.effect.set_cell_aspect(ratio);
+    }
+
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
         Some(&mut self.duration)
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.duration)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         self.effect.cell_selection()
     }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_timer(self.timer())
+            .with_done(self.done())
+            .with_children(vec![self.effect.describe()])
+    }
+
+    fn skip_all(&mut self) {
+        self.duration.finish();
+        self.effect.skip_all();
+    }
+
+    fn is_noop_this_frame(&self, delta: Duration) -> bool {
+        self.duration.remaining() > delta && self.effect.is_noop_this_frame(delta)
+    }
+
+    fn skip_current(&mut self) {
+        self.effect.skip_current();
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.effect.stage_index()
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.effect.stage_count()
+    }
 }
 
 pub trait IntoTemporaryEffect {
@@ -7,6 +7,7 @@ use crate::CellIterator;
 use crate::effect::{Effect, CellFilter};
 use crate::effect_timer::EffectTimer;
 use crate::interpolation::Interpolatable;
+use crate::EffectTree;
 use crate::shader::Shader;
 
 #[derive(Clone, Default)]
@@ -92,14 +93,56 @@ impl Shader for Translate {
         }
     }
 
+    fn set_cell_aspect(&mut self, ratio: f32) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.set_cell_aspect(ratio);
+        }
+    }
+
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
         Some(&mut self.lifetime)
     }
 
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.lifetime)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         if let Some(fx) = self.fx.as_ref() {
             return fx.cell_selection();
         }
         None
     }
-}
\ No newline at end of file
+
+    fn reduced(&self) -> Option<Effect> {
+        Some(crate::fx::cut(Effect::new(self.clone())))
+    }
+
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_timer(self.timer())
+            .with_done(self.done())
+            .with_children(self.fx.iter().map(Effect::describe).collect())
+    }
+
+    fn skip_current(&mut self) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.skip_current();
+        }
+    }
+
+    fn skip_all(&mut self) {
+        self.lifetime.finish();
+        if let Some(fx) = self.fx.as_mut() {
+            fx.skip_all();
+        }
+    }
+
+    fn stage_index(&self) -> Option<usize> {
+        self.fx.as_ref().and_then(Effect::stage_index)
+    }
+
+    fn stage_count(&self) -> Option<usize> {
+        self.fx.as_ref().and_then(Effect::stage_count)
+    }
+}
@@ -8,21 +8,40 @@ mod interpolation;
 mod effect;
 mod shader;
 mod effect_timer;
+mod effect_tree;
+mod frame_clock;
+mod offscreen_canvas;
 mod cell_iter;
 mod color_mapper;
 mod color_ext;
 mod rect_ext;
+mod motion;
 mod render_effect;
+mod render_buffer;
+mod blend_mode;
+mod particle_layer;
+mod area_stats;
+mod tween;
 
 pub mod fx;
 
+pub use area_stats::{analyze_area, AreaStats};
+pub use blend_mode::BlendMode;
 /// `CellIterator` provides an iterator over terminal cells.
 pub use cell_iter::CellIterator;
+pub use color_ext::{contrast_ratio, reset_color_resolver, set_color_resolver, ColorResolver, DefaultColorResolver};
 pub use color_mapper::ColorMapper;
-pub use effect::{Effect, CellFilter, IntoEffect};
-pub use effect_timer::EffectTimer;
-pub use rect_ext::CenteredShrink;
+pub use effect::{Effect, CellFilter, ClampPolicy, IntoEffect, OutOfBounds};
+pub use effect_timer::{EffectTimer, OvershootMode};
+pub use effect_tree::{EffectTree, EffectWarning, WarningKind};
+pub use frame_clock::FrameClock;
+pub use motion::{reduced_motion, set_reduced_motion};
+pub use offscreen_canvas::OffscreenCanvas;
+pub use particle_layer::{Particle, ParticleLayer};
+pub use rect_ext::{cell_aspect_ratio, set_cell_aspect_ratio, CenteredShrink};
+pub use render_buffer::BufferRenderer;
 pub use render_effect::EffectRenderer;
 pub use shader::Shader;
+pub use tween::Tween;
 pub use interpolation::*;
 
@@ -0,0 +1,38 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether effects honor a reduced-motion preference, e.g. for users prone
+/// to motion sickness. When enabled, effects moving content around run their
+/// accessible alternative instead, as returned by [Shader::reduced](crate::Shader::reduced):
+/// typically a fade, or an instant [cut](crate::fx::cut) to their final frame.
+/// Color effects are unaffected. Defaults to `false`. Individual effects can
+/// override it with [Effect::with_reduced_motion](crate::Effect::with_reduced_motion).
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, reduced_motion, set_reduced_motion, Shader};
+///
+/// assert!(!reduced_motion());
+/// set_reduced_motion(true);
+/// assert!(reduced_motion());
+///
+/// // the translation cuts to its destination in the first frame
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+/// let mut effect = fx::translate(Some(fx::fade_to_fg(Color::Red, 0)), (2, 0), 1000);
+/// effect.process(Duration::from_millis(16), &mut buf, Rect::new(0, 0, 2, 1));
+/// assert_eq!(buf.get(0, 0).fg, Color::Reset);
+/// assert_eq!(buf.get(2, 0).fg, Color::Red);
+/// ```
+pub fn set_reduced_motion(reduced: bool) {
+    REDUCED_MOTION.store(reduced, Ordering::Relaxed);
+}
+
+/// Returns whether effects honor a reduced-motion preference; see [set_reduced_motion].
+pub fn reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Offset, Position, Rect, Size};
+
+use crate::{BufferRenderer, Shader};
+
+/// An offscreen buffer for content larger than the visible area, such as maps
+/// or large animated scenes. Effects are processed against the canvas, and a
+/// viewport of it is rendered into the visible buffer, which makes it possible
+/// to pan across the canvas independently of the terminal size.
+///
+/// The canvas starts at the origin and grows on demand with [OffscreenCanvas::grow_to_fit];
+/// effects processed by the canvas are clipped to its bounds.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Position, Rect, Size};
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::{fx, OffscreenCanvas};
+///
+/// let mut canvas = OffscreenCanvas::new(Size::new(8, 1));
+/// canvas.buffer_mut().set_string(0, 0, "abcdefgh", Style::default());
+///
+/// // the effect extends beyond the canvas, writes are clipped to its bounds
+/// let mut fx = fx::fade_to_fg(Color::Red, 100);
+/// canvas.process(&mut fx, Duration::from_millis(100), Rect::new(4, 0, 10, 1));
+/// assert_eq!(canvas.buffer().get(3, 0).fg, Color::Reset);
+/// assert_eq!(canvas.buffer().get(7, 0).fg, Color::Red);
+///
+/// // render a 3 cell viewport, panned 2 cells into the canvas
+/// let mut screen = Buffer::empty(Rect::new(0, 0, 3, 1));
+/// canvas.render_viewport(Position::new(2, 0), &mut screen, Rect::new(0, 0, 3, 1));
+/// assert_eq!(screen.get(0, 0).symbol(), "c");
+/// assert_eq!(screen.get(2, 0).symbol(), "e");
+///
+/// canvas.grow_to_fit(Rect::new(0, 0, 12, 2));
+/// assert_eq!(canvas.area(), Rect::new(0, 0, 12, 2));
+/// assert_eq!(canvas.buffer().get(7, 0).symbol(), "h");
+/// ```
+#[derive(Clone, Debug)]
+pub struct OffscreenCanvas {
+    buf: Buffer,
+}
+
+impl OffscreenCanvas {
+    /// Creates a new, empty canvas of the given size.
+    pub fn new(size: Size) -> Self {
+        Self { buf: Buffer::empty(Rect::new(0, 0, size.width, size.height)) }
+    }
+
+    /// Returns the area of the canvas.
+    pub fn area(&self) -> Rect {
+        self.buf.area
+    }
+
+    /// Returns the content of the canvas.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buf
+    }
+
+    /// Returns the content of the canvas, e.g. for rendering widgets into it.
+    pub fn buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buf
+    }
+
+    /// Grows the canvas to contain `area`, keeping its current content. New cells are empty.
+    pub fn grow_to_fit(&mut self, area: Rect) {
+        let grown = self.buf.area.union(area);
+        if grown != self.buf.area {
+            let mut buf = Buffer::empty(grown);
+            buf.merge(&self.buf);
+            self.buf = buf;
+        }
+    }
+
+    /// Processes the effect against `area` of the canvas, clipped to the bounds
+    /// of the canvas. Returns the overflow of the effect, as [Shader::process].
+    /// Effects with an area of their own can be fitted to the canvas with a
+    /// [ClampPolicy](crate::ClampPolicy).
+    pub fn process<S: Shader>(
+        &mut self,
+        effect: &mut S,
+        duration: Duration,
+        area: Rect,
+    ) -> Option<Duration> {
+        let area = area.intersection(self.buf.area);
+        effect.process(duration, &mut self.buf, area)
+    }
+
+    /// Renders the part of the canvas starting at `viewport` into `area` of the
+    /// `target` buffer. Cells of `area` beyond the canvas are left untouched.
+    pub fn render_viewport(&self, viewport: Position, target: &mut Buffer, area: Rect) {
+        let offset = Offset { x: viewport.x as i32, y: viewport.y as i32 };
+        self.buf.render_buffer(offset, target, area);
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+
+use crate::EffectTimer;
+
+/// A single particle of a [ParticleLayer], occupying one cell for the
+/// duration of its lifetime.
+#[derive(Clone)]
+pub struct Particle<T = ()> {
+    /// The position of the particle, in buffer coordinates.
+    pub position: Position,
+    /// The remaining lifetime of the particle; the particle is culled once done.
+    pub lifetime: EffectTimer,
+    /// Custom per-particle state, e.g. a glyph or color.
+    pub data: T,
+}
+
+impl<T> Particle<T> {
+    /// Returns the progress of the particle's lifetime, from `0.0` when spawned
+    /// to `1.0` when it is about to be culled.
+    pub fn alpha(&self) -> f32 {
+        self.lifetime.alpha()
+    }
+}
+
+/// A bounded set of short-lived particles, each with a position and a lifetime,
+/// for building effects which animate cells individually, such as trails or
+/// sparkles.
+///
+/// The layer is a building block for shaders rather than an effect in itself:
+/// a shader spawns particles, calls [ParticleLayer::update] with the frame's
+/// duration, and then [ParticleLayer::render]s the particles into the buffer.
+/// Once the layer is full, spawning a particle culls the oldest one.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Position, Rect};
+/// use tachyonfx::ParticleLayer;
+///
+/// let area = Rect::new(0, 0, 3, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// let mut sparks: ParticleLayer<char> = ParticleLayer::new(2);
+/// sparks.spawn(Position::new(0, 0), 100.into(), '*');
+/// sparks.spawn(Position::new(1, 0), 300.into(), '+');
+/// sparks.spawn(Position::new(2, 0), 300.into(), 'x'); // culls the oldest particle
+///
+/// sparks.update(Duration::from_millis(200));
+/// sparks.render(&mut buf, area, |particle, cell| { cell.set_char(particle.data); });
+///
+/// assert_eq!(buf, Buffer::with_lines(vec![" +x"]));
+/// ```
+#[derive(Clone)]
+pub struct ParticleLayer<T = ()> {
+    particles: VecDeque<Particle<T>>,
+    max_particles: usize,
+}
+
+impl<T> ParticleLayer<T> {
+    /// Creates an empty layer holding at most `max_particles` particles.
+    pub fn new(max_particles: usize) -> Self {
+        Self {
+            particles: VecDeque::new(),
+            max_particles,
+        }
+    }
+
+    /// Spawns a particle, culling the oldest particle if the layer is full.
+    pub fn spawn(&mut self, position: Position, lifetime: EffectTimer, data: T) {
+        if self.max_particles == 0 {
+            return;
+        }
+
+        if self.particles.len() == self.max_particles {
+            self.particles.pop_front();
+        }
+
+        self.particles.push_back(Particle { position, lifetime, data });
+    }
+
+    /// Advances the lifetimes of all particles by `duration`, culling the
+    /// particles whose lifetime has run out.
+    pub fn update(&mut self, duration: Duration) {
+        self.particles.iter_mut().for_each(|p| { p.lifetime.process(duration); });
+        self.particles.retain(|p| !p.lifetime.done());
+    }
+
+    /// Calls `render_fn` with each particle inside `area` and the cell it occupies,
+    /// oldest particle first, so that newer particles are drawn on top.
+    pub fn render<F>(&self, buf: &mut Buffer, area: Rect, mut render_fn: F)
+        where F: FnMut(&Particle<T>, &mut Cell)
+    {
+        let area = area.intersection(buf.area);
+
+        self.particles.iter()
+            .filter(|p| area.contains(p.position))
+            .for_each(|p| render_fn(p, buf.get_mut(p.position.x, p.position.y)));
+    }
+
+    /// Returns an iterator over the particles, oldest first.
+    pub fn particles(&self) -> impl Iterator<Item = &Particle<T>> {
+        self.particles.iter()
+    }
+
+    /// Returns the most recently spawned particle, if any is alive.
+    pub fn newest(&self) -> Option<&Particle<T>> {
+        self.particles.back()
+    }
+
+    /// Returns the number of live particles.
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Returns `true` if there are no live particles.
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Culls the particles for which `f` returns `false`.
+    pub fn retain<F>(&mut self, f: F)
+        where F: FnMut(&Particle<T>) -> bool
+    {
+        self.particles.retain(f);
+    }
+
+    /// Culls all particles.
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+}
@@ -1,4 +1,9 @@
-use ratatui::layout::Rect;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use ratatui::layout::{Position, Rect};
+
+/// The default cell aspect ratio, stored as the bits of an `f32`; 0.5 width:height.
+static CELL_ASPECT_RATIO: AtomicU32 = AtomicU32::new(0x3f00_0000);
 
 /// A trait that provides a method to calculate a centered, shrunk rectangle
 /// within the bounds of the original rectangle.
@@ -34,3 +39,52 @@ impl CenteredShrink for Rect {
         Rect::new(x, y, width.min(self.width), height.min(self.height))
     }
 }
+
+/// Sets the default width:height aspect ratio of terminal cells, which is
+/// consulted by geometric effects, such as radial dissolves, to make circles
+/// look round. Defaults to `0.5`, i.e. cells twice as tall as they are wide.
+/// Individual effects can override it with [Effect::with_cell_aspect](crate::Effect::with_cell_aspect).
+///
+/// # Example
+/// ```
+/// use tachyonfx::{cell_aspect_ratio, set_cell_aspect_ratio};
+///
+/// assert_eq!(cell_aspect_ratio(), 0.5);
+/// set_cell_aspect_ratio(0.45);
+/// assert_eq!(cell_aspect_ratio(), 0.45);
+/// ```
+pub fn set_cell_aspect_ratio(ratio: f32) {
+    CELL_ASPECT_RATIO.store(ratio.to_bits(), Ordering::Relaxed);
+}
+
+/// Returns the default width:height aspect ratio of terminal cells; see [set_cell_aspect_ratio].
+pub fn cell_aspect_ratio() -> f32 {
+    f32::from_bits(CELL_ASPECT_RATIO.load(Ordering::Relaxed))
+}
+
+/// Returns the distance of the cell from the center of the area, in the range
+/// `0.0..=1.0`, where `1.0` is the distance to the corners. Distances are
+/// corrected for the width:height `aspect` ratio of cells, so that cells at the
+/// same distance form a circle rather than an ellipse.
+pub(crate) fn radial_distance(pos: Position, area: Rect, aspect: f32) -> f32 {
+    let y_scale = 1.0 / aspect.max(f32::EPSILON);
+    let distance = |x: f32, y: f32| (x * x + (y_scale * y) * (y_scale * y)).sqrt();
+
+    let half_w = area.width as f32 / 2.0;
+    let half_h = area.height as f32 / 2.0;
+    let dx = pos.x as f32 + 0.5 - (area.x as f32 + half_w);
+    let dy = pos.y as f32 + 0.5 - (area.y as f32 + half_h);
+
+    let max_distance = distance(half_w - 0.5, half_h - 0.5);
+    if max_distance > 0.0 {
+        (distance(dx, dy) / max_distance).min(1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Returns the positions of all cells in the area, row by row.
+pub(crate) fn positions(area: Rect) -> impl Iterator<Item = Position> {
+    (area.top()..area.bottom())
+        .flat_map(move |y| (area.left()..area.right()).map(move |x| Position::new(x, y)))
+}
@@ -0,0 +1,110 @@
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Offset, Rect};
+
+/// A trait for rendering the contents of one buffer into another.
+///
+/// The source is read starting at `offset`, relative to the source buffer's own
+/// area, which makes it possible to render a scrolled viewport of a large
+/// (virtual) buffer into a smaller area of the target buffer.
+pub trait BufferRenderer {
+
+    /// Renders the buffer into `area` of the `target` buffer. Cells of `area`
+    /// which map to positions outside the source buffer are left untouched.
+    ///
+    /// # Arguments
+    /// * `offset` - The position in the source buffer, relative to its area, which
+    ///   is rendered to the top-left corner of `area`.
+    /// * `target` - The buffer to render into.
+    /// * `area` - The area of the target buffer to render into; clipped to the target.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Offset, Rect};
+    /// use tachyonfx::BufferRenderer;
+    ///
+    /// let content = Buffer::with_lines(vec!["abc", "def", "ghi"]);
+    ///
+    /// let mut viewport = Buffer::with_lines(vec!["...", "..."]);
+    /// content.render_buffer(Offset { x: 1, y: 1 }, &mut viewport, Rect::new(0, 0, 3, 2));
+    ///
+    /// assert_eq!(viewport, Buffer::with_lines(vec!["ef.", "hi."]));
+    /// ```
+    fn render_buffer(&self, offset: Offset, target: &mut Buffer, area: Rect);
+
+    /// Renders the buffer into `area` of the `target` buffer, like
+    /// [BufferRenderer::render_buffer], but cells which map to positions outside
+    /// the source buffer are replaced with `default`.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::buffer::{Buffer, Cell};
+    /// use ratatui::layout::{Offset, Rect};
+    /// use tachyonfx::BufferRenderer;
+    ///
+    /// let content = Buffer::with_lines(vec!["abc", "def"]);
+    ///
+    /// let mut viewport = Buffer::with_lines(vec!["...", "..."]);
+    /// let mut blank = Cell::default();
+    /// blank.set_char('~');
+    /// content.render_buffer_or(Offset { x: 0, y: -1 }, &mut viewport, Rect::new(0, 0, 3, 2), &blank);
+    ///
+    /// assert_eq!(viewport, Buffer::with_lines(vec!["~~~", "abc"]));
+    /// ```
+    fn render_buffer_or(
+        &self,
+        offset: Offset,
+        target: &mut Buffer,
+        area: Rect,
+        default: &Cell,
+    );
+}
+
+impl BufferRenderer for Buffer {
+    fn render_buffer(&self, offset: Offset, target: &mut Buffer, area: Rect) {
+        blit_buffer(self, offset, target, area, None);
+    }
+
+    fn render_buffer_or(
+        &self,
+        offset: Offset,
+        target: &mut Buffer,
+        area: Rect,
+        default: &Cell,
+    ) {
+        blit_buffer(self, offset, target, area, Some(default));
+    }
+}
+
+fn blit_buffer(
+    src: &Buffer,
+    offset: Offset,
+    target: &mut Buffer,
+    area: Rect,
+    default: Option<&Cell>,
+) {
+    let area = area.intersection(target.area);
+    let src_area = src.area;
+
+    for y in 0..area.height {
+        for x in 0..area.width {
+            let src_x = src_area.x as i32 + offset.x + x as i32;
+            let src_y = src_area.y as i32 + offset.y + y as i32;
+
+            let inside_src = src_x >= src_area.left() as i32
+                && src_x < src_area.right() as i32
+                && src_y >= src_area.top() as i32
+                && src_y < src_area.bottom() as i32;
+
+            let cell = if inside_src {
+                src.get(src_x as u16, src_y as u16)
+            } else if let Some(default) = default {
+                default
+            } else {
+                continue;
+            };
+
+            *target.get_mut(area.x + x, area.y + y) = cell.clone();
+        }
+    }
+}
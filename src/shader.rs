@@ -3,8 +3,8 @@ use ratatui::buffer::{Buffer};
 use ratatui::layout::{Rect};
 use crate::cell_iter::CellIterator;
 
-use crate::effect::CellFilter;
-use crate::EffectTimer;
+use crate::effect::{CellFilter, Effect};
+use crate::{EffectTimer, EffectTree};
 
 /// A trait representing a shader-like object that can be processed for a duration.
 /// The `Shader` trait defines the interface for objects that can apply visual effects
@@ -29,11 +29,17 @@ pub trait Shader {
     /// use std::time::Duration;
     /// use ratatui::buffer::Buffer;
     /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
     ///
-    /// let mut shader = MyShader::new();
+    /// let mut shader = fx::fade_to_fg(Color::Red, 500);
     /// let area = Rect::new(0, 0, 10, 10);
     /// let mut buffer = Buffer::empty(area);
+    ///
     /// let overflow = shader.process(Duration::from_millis(100), &mut buffer, area);
+    /// assert_eq!(overflow, None);
+    /// let overflow = shader.process(Duration::from_millis(500), &mut buffer, area);
+    /// assert_eq!(overflow, Some(Duration::from_millis(100)));
     /// ```
     fn process(
         &mut self,
@@ -55,7 +61,10 @@ pub trait Shader {
     /// the actual shader logic should be implemented.
     ///
     /// # Arguments
-    /// * `alpha` - The alpha value indicating the progress of the shader effect.
+    /// * `alpha` - The alpha value indicating the progress of the shader effect. It is
+    ///   computed once per call to `process`, with the timer's interpolation already
+    ///   applied, so shaders with a spatially uniform progress should use it as-is
+    ///   rather than re-evaluating the easing function per cell.
     /// * `area` - The rectangular area within the buffer where the shader will be applied.
     /// * `cell_iter` - An iterator over the cells in the specified area.
     fn execute(
@@ -118,10 +127,12 @@ pub trait Shader {
     ///
     /// # Example
     /// ```
-    /// use tachyonfx::CellFilter;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, CellFilter, Shader};
     ///
-    /// let mut shader = MyShader::new();
-    /// shader.cell_selection(CellFilter::Not(CellFilter::Text));
+    /// let mut shader = fx::fade_to_fg(Color::Red, 500);
+    /// shader.set_cell_selection(CellFilter::Not(Box::new(CellFilter::Text)));
+    /// assert!(matches!(shader.cell_selection(), Some(CellFilter::Not(_))));
     /// ```
     fn set_cell_selection(&mut self, filter: CellFilter);
 
@@ -139,17 +150,255 @@ pub trait Shader {
     ///
     /// # Example
     /// ```
-    /// let mut shader = MyShader::new();
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 1, 1);
+    /// let mut shader = fx::fade_to_fg(Color::Red, 500);
+    /// shader.process(Duration::from_millis(250), &mut Buffer::empty(area), area);
+    ///
     /// if let Some(timer) = shader.timer_mut() {
     ///     timer.reset();
     /// }
+    /// assert_eq!(shader.timer_mut().unwrap().alpha(), 0.0);
     /// ```
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> { None }
 
+    /// Returns a copy of the shader's timer, if any.
+    fn timer(&self) -> Option<EffectTimer> { None }
+
 
     /// Returns the cell selection strategy for the shader, if any.
     ///
     /// # Returns
     /// * An `Option` containing the shader's `CellFilter`, or `None` if not applicable.
     fn cell_selection(&self) -> Option<CellFilter> { None }
+
+    /// Returns the accessible alternative of a shader moving content around,
+    /// which an [Effect] runs in its place while a reduced-motion preference
+    /// applies; see [set_reduced_motion](crate::set_reduced_motion). This is
+    /// typically a fade, or an instant [cut](crate::fx::cut) to the final frame.
+    /// The default implementation returns `None`, leaving the shader as it is.
+    fn reduced(&self) -> Option<Effect> { None }
+
+    /// Returns a snapshot of the shader and any nested effects, including the
+    /// current progress of their timers, for debugging. Shaders wrapping other
+    /// effects include them as children.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, CellFilter, Shader};
+    ///
+    /// let mut effect = fx::sequence(vec![
+    ///     fx::fade_to_fg(Color::Red, 1000).with_cell_selection(CellFilter::Text),
+    ///     fx::never_complete(fx::sleep(500)),
+    /// ]);
+    ///
+    /// let area = Rect::new(0, 0, 1, 1);
+    /// effect.process(Duration::from_millis(250), &mut Buffer::empty(area), area);
+    ///
+    /// assert_eq!(effect.describe().to_string(), [
+    ///     "SequentialEffect",
+    ///     "  FadeColors 250ms/1s (25%) [Text]",
+    ///     "  NeverComplete (infinite)",
+    ///     "    Sleep 0ns/500ms (0%)",
+    /// ].join("\n"));
+    /// ```
+    fn describe(&self) -> EffectTree {
+        EffectTree::new(std::any::type_name::<Self>())
+            .with_timer(self.timer())
+            .with_cell_filter(self.cell_selection())
+            .with_done(self.done())
+    }
+
+    /// Overrides the width:height aspect ratio of cells assumed by geometric
+    /// shaders, such as radial dissolves, in place of the crate-wide default set
+    /// with [set_cell_aspect_ratio](crate::set_cell_aspect_ratio). Shaders without
+    /// geometry ignore it, and shaders wrapping other effects pass it on.
+    fn set_cell_aspect(&mut self, _ratio: f32) {}
+
+    /// Returns `true` if processing the shader for `delta` is known to leave the
+    /// rendered output unchanged, and to consume all of `delta`, so that no part
+    /// of it overflows into subsequent effects. Event-driven applications can use
+    /// this to skip redrawing frames, e.g. while a sequence is sleeping.
+    ///
+    /// This check must be cheap and must not mutate the shader; shaders which
+    /// cannot determine it cheaply return `false`, which is the default.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 1, 1);
+    /// let mut buf = Buffer::empty(area);
+    /// let frame = Duration::from_millis(100);
+    /// let mut effect = fx::sequence(vec![
+    ///     fx::sleep(250),
+    ///     fx::fade_to_fg(Color::Red, 500),
+    /// ]);
+    ///
+    /// assert!(effect.is_noop_this_frame(frame));
+    /// effect.process(frame, &mut buf, area);
+    /// assert!(effect.is_noop_this_frame(frame));
+    /// effect.process(frame, &mut buf, area);
+    ///
+    /// // 50ms remain of the sleep; the rest of the frame goes to the fade
+    /// assert!(!effect.is_noop_this_frame(frame));
+    ///
+    /// // the fade changes the output every frame
+    /// effect.process(frame, &mut buf, area);
+    /// assert!(!effect.is_noop_this_frame(frame));
+    /// ```
+    fn is_noop_this_frame(&self, _delta: Duration) -> bool { false }
+
+    /// Requests that the currently running step of the shader is completed, e.g.
+    /// when the user presses a key to skip an intro. The step is snapped to its
+    /// final frame on the next call to `process`, after which the shader continues
+    /// with the following step. The default implementation does nothing; it is
+    /// implemented by [sequence](crate::fx::sequence) and forwarded by the effects
+    /// wrapping other effects. An [Effect](crate::Effect) without stages treats
+    /// itself as the current step, and completes as with [Shader::skip_all].
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 1, 1);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut intro = fx::sequence(vec![
+    ///     fx::fade_to_fg(Color::Red, 1000),
+    ///     fx::fade_to_fg(Color::Blue, 1000),
+    /// ]);
+    ///
+    /// intro.process(Duration::from_millis(100), &mut buf, area);
+    /// intro.skip_current();
+    ///
+    /// // the first step snaps to red; the second step then starts fading from red
+    /// intro.process(Duration::ZERO, &mut buf, area);
+    /// assert_eq!(buf.get(0, 0).fg, Color::Red);
+    /// assert!(intro.running());
+    /// ```
+    fn skip_current(&mut self) {}
+
+    /// Requests that all remaining steps of the shader are completed. On the next
+    /// call to `process`, every remaining step is snapped to its final frame, in
+    /// order, leaving the buffer in the shader's final state. No time passes for
+    /// skipped effects: repeats end after their current iteration, and effects
+    /// never completing on their own are passed over by the sequence containing
+    /// them. The default implementation finishes the shader's [timer](Shader::timer_mut).
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 1, 1);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut intro = fx::sequence(vec![
+    ///     fx::fade_to_fg(Color::Red, 1000),
+    ///     fx::fade_to_fg(Color::Blue, 1000),
+    /// ]);
+    ///
+    /// intro.skip_all();
+    /// intro.process(Duration::from_millis(16), &mut buf, area);
+    /// assert_eq!(buf.get(0, 0).fg, Color::Blue);
+    /// assert!(intro.done());
+    ///
+    /// // repeats and never completing effects are skipped too, without their marks firing
+    /// let cued = std::rc::Rc::new(std::cell::Cell::new(false));
+    /// let cue = cued.clone();
+    /// let mut intro = fx::sequence(vec![
+    ///     fx::repeat_ramp(fx::fade_to_fg(Color::Red, 1000), 3, |_| 1.0)
+    ///         .at(Duration::from_millis(1500), move || cue.set(true)),
+    ///     fx::never_complete(fx::fade_to_fg(Color::Green, 100)),
+    ///     fx::fade_to_fg(Color::Blue, 1000),
+    /// ]);
+    ///
+    /// intro.process(Duration::from_millis(100), &mut buf, area);
+    /// intro.skip_all();
+    /// intro.process(Duration::ZERO, &mut buf, area);
+    /// assert_eq!(buf.get(0, 0).fg, Color::Blue);
+    /// assert!(intro.done());
+    /// assert!(!cued.get());
+    ///
+    /// // effects wrapped by other effects, or running in parallel, are skipped along
+    /// let mut intro = fx::sequence(vec![
+    ///     fx::parallel(vec![
+    ///         fx::fade_to_fg(Color::Red, 1000),
+    ///         fx::ping_pong(fx::fade_to(Color::Green, Color::Blue, 1000)),
+    ///     ]),
+    ///     fx::sleep(1000),
+    /// ]);
+    ///
+    /// intro.skip_current();
+    /// intro.process(Duration::ZERO, &mut buf, area);
+    /// assert_eq!(buf.get(0, 0).fg, Color::Red);
+    /// assert_eq!(buf.get(0, 0).bg, Color::Reset);
+    /// assert_eq!(intro.stage_index(), Some(1));
+    /// ```
+    fn skip_all(&mut self) {
+        if let Some(timer) = self.timer_mut() {
+            timer.finish();
+        }
+    }
+
+    /// Returns the zero-based index of the currently running stage, for shaders
+    /// running their effects in stages, e.g. to show "step 2 of 5" alongside a
+    /// multi-step intro. Finished sequences report their last stage. Nested
+    /// sequences are not consulted; the index is that of the top-level stage.
+    /// The default implementation returns `None`; it is implemented by
+    /// [sequence](crate::fx::sequence) and [sequence_overlap](crate::fx::sequence_overlap),
+    /// and forwarded by the effects wrapping other effects.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 1, 1);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut tutorial = fx::sequence(vec![
+    ///     fx::fade_to_fg(Color::Red, 1000),
+    ///     fx::sequence(vec![fx::sleep(500), fx::sleep(500)]),
+    ///     fx::fade_to_fg(Color::Blue, 1000),
+    /// ]);
+    /// assert_eq!((tutorial.stage_index(), tutorial.stage_count()), (Some(0), Some(3)));
+    ///
+    /// tutorial.process(Duration::from_millis(1600), &mut buf, area);
+    /// assert_eq!(tutorial.stage_index(), Some(1));
+    ///
+    /// tutorial.process(Duration::from_secs(5), &mut buf, area);
+    /// assert_eq!(tutorial.stage_index(), Some(2));
+    /// assert!(fx::sleep(100).stage_index().is_none());
+    ///
+    /// // wrapping effects report the stage of the effect they wrap
+    /// let mut looping = fx::never_complete(fx::sequence(vec![fx::sleep(500), fx::sleep(500)]));
+    /// looping.process(Duration::from_millis(600), &mut buf, area);
+    /// assert_eq!((looping.stage_index(), looping.stage_count()), (Some(1), Some(2)));
+    /// ```
+    fn stage_index(&self) -> Option<usize> { None }
+
+    /// Returns the number of stages of the shader; see [Shader::stage_index].
+    fn stage_count(&self) -> Option<usize> { None }
 }
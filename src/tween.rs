@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use crate::effect_timer::EffectTimer;
+use crate::interpolation::Interpolatable;
+
+/// Animates a single value from one state to another, independent of any buffer.
+///
+/// A `Tween` uses the same [EffectTimer] and interpolation as effects, making it
+/// possible to drive e.g. a scroll offset or a layout dimension with the same
+/// easing curves as the effects rendered alongside it. The value only depends on
+/// the elapsed time, not on the number of ticks.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use tachyonfx::{Interpolation, Tween};
+///
+/// let mut height = Tween::new(0_u16, 20, (1000, Interpolation::Linear));
+/// assert_eq!(height.value(), 0);
+///
+/// assert_eq!(height.tick(Duration::from_millis(250)), 5);
+/// assert_eq!(height.tick(Duration::from_millis(250)), 10);
+/// assert!(!height.done());
+///
+/// assert_eq!(height.tick(Duration::from_secs(5)), 20);
+/// assert!(height.done());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    timer: EffectTimer,
+}
+
+impl<T: Interpolatable<T> + Clone> Tween<T> {
+    /// Creates a new tween from `from` to `to`, eased according to the
+    /// interpolation of the `timer`.
+    pub fn new<E: Into<EffectTimer>>(from: T, to: T, timer: E) -> Self {
+        Self { from, to, timer: timer.into() }
+    }
+
+    /// Advances the tween by `delta` and returns the updated value.
+    pub fn tick(&mut self, delta: Duration) -> T {
+        self.timer.process(delta);
+        self.value()
+    }
+
+    /// Returns the current value, without advancing the tween.
+    pub fn value(&self) -> T {
+        // the end points are returned as-is, avoiding rounding errors
+        let alpha = self.timer.alpha();
+        if alpha == 0.0 {
+            self.from.clone()
+        } else if alpha == 1.0 {
+            self.to.clone()
+        } else {
+            self.from.lerp(&self.to, alpha)
+        }
+    }
+
+    /// Returns `true` once the tween has reached its end value.
+    pub fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    /// Restarts the tween from its initial value.
+    pub fn reset(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Returns the timer driving the tween.
+    pub fn timer(&self) -> EffectTimer {
+        self.timer
+    }
+}